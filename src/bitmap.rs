@@ -1,4 +1,8 @@
-use crate::{ffi, Error, FtResult};
+use crate::{ffi, ffi_ext, Color, Error, FtResult, Library, Vector};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Deref;
+use std::ptr;
 use std::slice;
 
 /// An enumeration type used to describe the format of pixels in a given bitmap. Note that
@@ -48,6 +52,119 @@ pub enum PixelMode {
     Bgra,
 }
 
+impl PixelMode {
+    pub(crate) fn to_raw(self) -> u32 {
+        match self {
+            PixelMode::None => ffi::FT_PIXEL_MODE_NONE,
+            PixelMode::Mono => ffi::FT_PIXEL_MODE_MONO,
+            PixelMode::Gray => ffi::FT_PIXEL_MODE_GRAY,
+            PixelMode::Gray2 => ffi::FT_PIXEL_MODE_GRAY2,
+            PixelMode::Gray4 => ffi::FT_PIXEL_MODE_GRAY4,
+            PixelMode::Lcd => ffi::FT_PIXEL_MODE_LCD,
+            PixelMode::LcdV => ffi::FT_PIXEL_MODE_LCD_V,
+            PixelMode::Bgra => ffi::FT_PIXEL_MODE_BGRA,
+        }
+    }
+
+    /// The number of bytes needed for `width` pixels in this mode, or `None` for
+    /// `PixelMode::None`.
+    pub(crate) fn row_bytes(self, width: usize) -> Option<usize> {
+        match self {
+            PixelMode::None => None,
+            PixelMode::Mono => Some(width.div_ceil(8)),
+            PixelMode::Gray2 => Some(width.div_ceil(4)),
+            PixelMode::Gray4 => Some(width.div_ceil(2)),
+            PixelMode::Gray | PixelMode::Lcd | PixelMode::LcdV => Some(width),
+            PixelMode::Bgra => width.checked_mul(4),
+        }
+    }
+
+    pub(crate) fn num_grays(self) -> i16 {
+        match self {
+            PixelMode::Gray | PixelMode::Lcd | PixelMode::LcdV => 256,
+            PixelMode::Gray2 => 4,
+            PixelMode::Gray4 => 16,
+            PixelMode::Mono => 2,
+            PixelMode::None | PixelMode::Bgra => 0,
+        }
+    }
+}
+
+/// A single pixel of a `Bitmap`, in a representation that does not depend on the pixel mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pixel {
+    /// The coverage of a Mono, Gray, Gray2 or Gray4 pixel, scaled to the range 0 (empty) to
+    /// 255 (fully covered).
+    Coverage(u8),
+    /// The red, green and blue subpixel coverage of an Lcd or LcdV pixel, each scaled to the
+    /// range 0 to 255.
+    Subpixel { red: u8, green: u8, blue: u8 },
+    /// The color of a Bgra pixel, with the premultiplication undone.
+    Color(Color),
+}
+
+/// Scale a value with `levels` levels to the range 0 to 255.
+fn scale_coverage(value: u8, levels: u32) -> u8 {
+    if levels <= 1 || levels >= 256 {
+        value
+    } else {
+        (value as u32 * 255 / (levels - 1)).min(255) as u8
+    }
+}
+
+/// Undo the premultiplication of a color channel.
+pub(crate) fn unpremultiply(value: u8, alpha: u8) -> u8 {
+    if alpha == 0 {
+        0
+    } else {
+        ((value as u32 * 255 + alpha as u32 / 2) / alpha as u32).min(255) as u8
+    }
+}
+
+/// Whether the color channels of an RGBA buffer are multiplied by its alpha channel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AlphaMode {
+    Premultiplied,
+    Straight,
+}
+
+/// A lookup table applied to coverage values before they are turned into alpha by
+/// `Bitmap::to_rgba_with_table`, usually to correct for gamma or to increase contrast.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CoverageTable {
+    table: [u8; 256],
+}
+
+impl CoverageTable {
+    /// Build a table that first increases the contrast of the coverage by `contrast` (0 leaves
+    /// it unchanged, 1 is the maximum) and then applies a gamma of `gamma`, so that values
+    /// above 1 make text heavier.
+    pub fn new(gamma: f32, contrast: f32) -> Self {
+        let mut table = [0; 256];
+        for (i, value) in table.iter_mut().enumerate() {
+            let coverage = i as f32 / 255.0;
+            let coverage = (coverage + contrast * coverage * (1.0 - coverage)).clamp(0.0, 1.0);
+            *value = (coverage.powf(1.0 / gamma) * 255.0).round() as u8;
+        }
+        CoverageTable { table }
+    }
+
+    /// Use a table of precomputed values, indexed by coverage.
+    pub fn from_table(table: [u8; 256]) -> Self {
+        CoverageTable { table }
+    }
+
+    /// The corrected value of `coverage`.
+    pub fn apply(&self, coverage: u8) -> u8 {
+        self.table[coverage as usize]
+    }
+}
+
+/// Multiply two values in the range 0 to 255, rounding to the nearest value.
+fn mul_u8(a: u8, b: u8) -> u8 {
+    ((a as u32 * b as u32 + 127) / 255) as u8
+}
+
 #[allow(missing_copy_implementations)]
 pub struct Bitmap {
     raw: *const ffi::FT_Bitmap,
@@ -117,4 +234,714 @@ impl Bitmap {
     pub fn pitch(&self) -> i32 {
         unsafe { (*self.raw).pitch }
     }
+
+    /// The size of the bitmap in whole pixels, as indexed by `pixel`. This differs from `width`
+    /// and `rows` for Lcd and LcdV bitmaps, which store three subpixels per pixel.
+    pub fn pixel_dimensions(&self) -> (usize, usize) {
+        let (width, rows) = (self.width().max(0) as usize, self.rows().max(0) as usize);
+        match self.pixel_mode() {
+            Ok(PixelMode::Lcd) => (width / 3, rows),
+            Ok(PixelMode::LcdV) => (width, rows / 3),
+            _ => (width, rows),
+        }
+    }
+
+    /// The bytes of the row `y` rows from the top, whatever the direction of the pitch.
+    fn row(&self, y: usize) -> &[u8] {
+        let rows = self.rows() as usize;
+        assert!(y < rows, "bitmap row {} out of range", y);
+        let stride = self.pitch().unsigned_abs() as usize;
+        // With a negative pitch the bottom row comes first in memory.
+        let index = if self.pitch() < 0 { rows - 1 - y } else { y };
+        &self.buffer()[index * stride..(index + 1) * stride]
+    }
+
+    fn pixel_in_mode(&self, mode: PixelMode, x: usize, y: usize) -> Pixel {
+        let num_grays = unsafe { (*self.raw).num_grays } as u32;
+        match mode {
+            PixelMode::Mono => {
+                let byte = self.row(y)[x / 8];
+                Pixel::Coverage(if byte & (0x80 >> (x % 8)) != 0 {
+                    255
+                } else {
+                    0
+                })
+            }
+            PixelMode::Gray2 => {
+                let byte = self.row(y)[x / 4];
+                Pixel::Coverage(scale_coverage((byte >> (6 - 2 * (x % 4))) & 0x03, 4))
+            }
+            PixelMode::Gray4 => {
+                let byte = self.row(y)[x / 2];
+                Pixel::Coverage(scale_coverage((byte >> (4 - 4 * (x % 2))) & 0x0F, 16))
+            }
+            PixelMode::Gray => Pixel::Coverage(scale_coverage(self.row(y)[x], num_grays)),
+            PixelMode::Lcd => {
+                let row = self.row(y);
+                Pixel::Subpixel {
+                    red: scale_coverage(row[3 * x], num_grays),
+                    green: scale_coverage(row[3 * x + 1], num_grays),
+                    blue: scale_coverage(row[3 * x + 2], num_grays),
+                }
+            }
+            PixelMode::LcdV => Pixel::Subpixel {
+                red: scale_coverage(self.row(3 * y)[x], num_grays),
+                green: scale_coverage(self.row(3 * y + 1)[x], num_grays),
+                blue: scale_coverage(self.row(3 * y + 2)[x], num_grays),
+            },
+            PixelMode::Bgra => {
+                let bgra = &self.row(y)[4 * x..4 * x + 4];
+                let alpha = bgra[3];
+                Pixel::Color(Color {
+                    red: unpremultiply(bgra[2], alpha),
+                    green: unpremultiply(bgra[1], alpha),
+                    blue: unpremultiply(bgra[0], alpha),
+                    alpha,
+                })
+            }
+            PixelMode::None => panic!("bitmap has no pixel mode"),
+        }
+    }
+
+    /// The pixel `x` pixels from the left and `y` pixels from the top of the bitmap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are outside of `pixel_dimensions`, or if the bitmap has no
+    /// valid pixel mode.
+    pub fn pixel(&self, x: usize, y: usize) -> Pixel {
+        let (width, rows) = self.pixel_dimensions();
+        assert!(x < width && y < rows, "pixel ({}, {}) out of range", x, y);
+        let mode = self.pixel_mode().expect("bitmap has no valid pixel mode");
+        self.pixel_in_mode(mode, x, y)
+    }
+
+    /// Iterate over the rows of the bitmap from top to bottom, each yielding its pixels from
+    /// left to right as `pixel` would.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bitmap has no valid pixel mode.
+    pub fn rows_iter(&self) -> PixelRows<'_> {
+        let mode = self.pixel_mode().expect("bitmap has no valid pixel mode");
+        let (width, rows) = self.pixel_dimensions();
+        PixelRows {
+            bitmap: self,
+            mode,
+            width,
+            y: 0,
+            rows,
+        }
+    }
+
+    /// Convert the bitmap into a tightly packed, top-down RGBA buffer of `pixel_dimensions`,
+    /// with four bytes per pixel.
+    ///
+    /// Coverage bitmaps are tinted with `color`. Bgra bitmaps keep their own colors, and only
+    /// the alpha of `color` is applied to them.
+    ///
+    /// Lcd and LcdV bitmaps need a separate alpha for each color channel, which a single RGBA
+    /// color cannot express, so they are converted to a subpixel mask instead: red, green and
+    /// blue hold the alpha of the respective channel, scaled by the alpha of `color`, and alpha
+    /// holds the largest of the three. The color channels of `color` and `alpha_mode` do not
+    /// apply. Draw the mask with dual-source blending, using `color` multiplied by the mask as
+    /// the first source, the mask as the second source, and blend factors `ONE` and
+    /// `ONE_MINUS_SRC1_COLOR`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bitmap has no valid pixel mode.
+    pub fn to_rgba(&self, color: Color, alpha_mode: AlphaMode) -> Vec<u8> {
+        self.rgba(color, alpha_mode, None)
+    }
+
+    /// Like `to_rgba`, but pass coverage values through `table` first. The table is not
+    /// applied to Bgra bitmaps.
+    pub fn to_rgba_with_table(
+        &self,
+        color: Color,
+        alpha_mode: AlphaMode,
+        table: &CoverageTable,
+    ) -> Vec<u8> {
+        self.rgba(color, alpha_mode, Some(table))
+    }
+
+    fn rgba(&self, color: Color, alpha_mode: AlphaMode, table: Option<&CoverageTable>) -> Vec<u8> {
+        let (width, rows) = self.pixel_dimensions();
+        let mut rgba = Vec::with_capacity(width * rows * 4);
+        let alpha_of = |coverage: u8| {
+            let coverage = table.map_or(coverage, |table| table.apply(coverage));
+            mul_u8(coverage, color.alpha)
+        };
+        if self.pixel_mode() == Ok(PixelMode::Bgra) {
+            // Work on the stored premultiplied values, so that premultiplied output is exact.
+            for y in 0..rows {
+                for bgra in self.row(y)[..width * 4].chunks_exact(4) {
+                    let alpha = mul_u8(bgra[3], color.alpha);
+                    let [blue, green, red] =
+                        [bgra[0], bgra[1], bgra[2]].map(|c| match alpha_mode {
+                            AlphaMode::Premultiplied => mul_u8(c, color.alpha),
+                            AlphaMode::Straight => unpremultiply(c, bgra[3]),
+                        });
+                    rgba.extend_from_slice(&[red, green, blue, alpha]);
+                }
+            }
+            return rgba;
+        }
+        for pixel in self.rows_iter().flatten() {
+            match pixel {
+                Pixel::Coverage(coverage) => {
+                    let alpha = alpha_of(coverage);
+                    let [red, green, blue] =
+                        [color.red, color.green, color.blue].map(|channel| match alpha_mode {
+                            AlphaMode::Premultiplied => mul_u8(channel, alpha),
+                            AlphaMode::Straight if alpha == 0 => 0,
+                            AlphaMode::Straight => channel,
+                        });
+                    rgba.extend_from_slice(&[red, green, blue, alpha]);
+                }
+                Pixel::Subpixel { red, green, blue } => {
+                    let [red, green, blue] = [red, green, blue].map(alpha_of);
+                    rgba.extend_from_slice(&[red, green, blue, red.max(green).max(blue)]);
+                }
+                Pixel::Color(_) => unreachable!(),
+            }
+        }
+        rgba
+    }
+
+    /// Copy the bitmap into an `OwnedBitmap`, which stays valid when the glyph slot or glyph it
+    /// came from is reused or dropped.
+    pub fn to_owned(&self, library: &Library) -> FtResult<OwnedBitmap> {
+        unsafe { OwnedBitmap::copy_raw(library.raw(), self.raw) }
+    }
+}
+
+/// An iterator over the rows of a `Bitmap`, returned by `Bitmap::rows_iter`.
+pub struct PixelRows<'a> {
+    bitmap: &'a Bitmap,
+    mode: PixelMode,
+    width: usize,
+    y: usize,
+    rows: usize,
+}
+
+impl<'a> Iterator for PixelRows<'a> {
+    type Item = PixelRow<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.rows {
+            return None;
+        }
+        let row = PixelRow {
+            bitmap: self.bitmap,
+            mode: self.mode,
+            x: 0,
+            width: self.width,
+            y: self.y,
+        };
+        self.y += 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rows - self.y;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PixelRows<'_> {}
+
+/// The pixels of one bitmap row, from left to right.
+pub struct PixelRow<'a> {
+    bitmap: &'a Bitmap,
+    mode: PixelMode,
+    x: usize,
+    width: usize,
+    y: usize,
+}
+
+impl Iterator for PixelRow<'_> {
+    type Item = Pixel;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x >= self.width {
+            return None;
+        }
+        let pixel = self.bitmap.pixel_in_mode(self.mode, self.x, self.y);
+        self.x += 1;
+        Some(pixel)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.width - self.x;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PixelRow<'_> {}
+
+/// A bitmap that owns its buffer, released with `FT_Bitmap_Done` when dropped. It dereferences
+/// to `Bitmap` for read access.
+///
+/// An owned bitmap also has an origin, the position of its top left corner in 26.6 pixels,
+/// which is used and updated by `blend`.
+pub struct OwnedBitmap {
+    library_raw: ffi::FT_Library,
+    // Allocated with `Box::into_raw` so that `view` can point to it while the `OwnedBitmap`
+    // moves around. Only ever accessed through this pointer.
+    raw: *mut ffi::FT_Bitmap,
+    view: Bitmap,
+    origin: Vector,
+}
+
+impl OwnedBitmap {
+    /// Create an empty bitmap, for example as the target of `blend`.
+    pub fn new(library: &Library) -> Self {
+        unsafe { Self::init(library.raw()) }
+    }
+
+    unsafe fn init(library_raw: ffi::FT_Library) -> Self {
+        ffi::FT_Reference_Library(library_raw);
+        let raw = Box::into_raw(Box::new(mem::zeroed::<ffi::FT_Bitmap>()));
+        ffi_ext::FT_Bitmap_Init(raw);
+        let view = Bitmap::from_raw(raw);
+        OwnedBitmap {
+            library_raw,
+            raw,
+            view,
+            origin: Vector { x: 0, y: 0 },
+        }
+    }
+
+    unsafe fn copy_raw(
+        library_raw: ffi::FT_Library,
+        source: *const ffi::FT_Bitmap,
+    ) -> FtResult<Self> {
+        let bitmap = Self::init(library_raw);
+        let err = ffi_ext::FT_Bitmap_Copy(library_raw, source, bitmap.raw);
+        if err == ffi::FT_Err_Ok {
+            Ok(bitmap)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Convert the bitmap to an 8-bit `PixelMode::Gray` bitmap whose rows are padded to a
+    /// multiple of `alignment` bytes.
+    ///
+    /// Mono, Gray2 and Gray4 pixel values are copied as they are, so the result has 2, 4 or
+    /// 16 gray levels as reported by `num_grays`.
+    pub fn convert(&self, alignment: i32) -> FtResult<OwnedBitmap> {
+        unsafe {
+            let mut bitmap = Self::init(self.library_raw);
+            let err = ffi_ext::FT_Bitmap_Convert(self.library_raw, self.raw, bitmap.raw, alignment);
+            if err == ffi::FT_Err_Ok {
+                // FreeType leaves the padding at the end of each row uninitialized.
+                let width = bitmap.width() as usize;
+                let pitch = bitmap.pitch().unsigned_abs() as usize;
+                if pitch > width {
+                    for row in bitmap.buffer_mut().chunks_mut(pitch) {
+                        row[width..].fill(0);
+                    }
+                }
+                bitmap.origin = self.origin;
+                Ok(bitmap)
+            } else {
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Make the bitmap bolder by `x_strength` and `y_strength`, in 26.6 pixels rounded to whole
+    /// pixels. The bitmap grows to the right and to the top accordingly.
+    pub fn embolden(&mut self, x_strength: ffi::FT_Pos, y_strength: ffi::FT_Pos) -> FtResult<()> {
+        let err = unsafe {
+            ffi_ext::FT_Bitmap_Embolden(self.library_raw, self.raw, x_strength, y_strength)
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Tint `source` with `color` and blend it over this bitmap, which must be empty or a
+    /// `PixelMode::Bgra` bitmap. `source_offset` is the position of the top left corner of
+    /// `source` in 26.6 pixels, rounded down to whole pixels. This bitmap grows as needed to
+    /// hold the result, and its origin moves accordingly.
+    pub fn blend(&mut self, source: &Bitmap, source_offset: Vector, color: Color) -> FtResult<()> {
+        let err = unsafe {
+            ffi_ext::FT_Bitmap_Blend(
+                self.library_raw,
+                source.raw(),
+                source_offset,
+                self.raw,
+                &mut self.origin,
+                color.into(),
+            )
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// The number of gray levels of a gray bitmap.
+    pub fn num_grays(&self) -> i16 {
+        unsafe { (*self.raw).num_grays }
+    }
+
+    /// The position of the bitmap's top left corner, in 26.6 pixels.
+    pub fn origin(&self) -> Vector {
+        self.origin
+    }
+
+    pub fn set_origin(&mut self, origin: Vector) {
+        self.origin = origin;
+    }
+
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        let raw = unsafe { &*self.raw };
+        let size = raw.pitch.unsigned_abs() as usize * raw.rows as usize;
+        if size == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(raw.buffer, size) }
+    }
+}
+
+impl Deref for OwnedBitmap {
+    type Target = Bitmap;
+
+    fn deref(&self) -> &Bitmap {
+        &self.view
+    }
+}
+
+impl Clone for OwnedBitmap {
+    fn clone(&self) -> Self {
+        match unsafe { Self::copy_raw(self.library_raw, self.raw) } {
+            Ok(mut bitmap) => {
+                bitmap.origin = self.origin;
+                bitmap
+            }
+            Err(_) => panic!("Failed to copy bitmap"),
+        }
+    }
+}
+
+impl Drop for OwnedBitmap {
+    fn drop(&mut self) {
+        let err = unsafe { ffi_ext::FT_Bitmap_Done(self.library_raw, self.raw) };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop bitmap");
+        }
+        let err = unsafe {
+            drop(Box::from_raw(self.raw));
+            ffi::FT_Done_Library(self.library_raw)
+        };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop library")
+        }
+    }
+}
+
+/// A bitmap backed by a caller-owned buffer, for example a region of a glyph atlas, that
+/// FreeType can draw into.
+pub struct BitmapMut<'a> {
+    raw: ffi::FT_Bitmap,
+    _phantom: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> BitmapMut<'a> {
+    /// Wrap `buffer` as a bitmap of `rows` rows of `width` pixels each. Rows start every
+    /// `pitch` bytes; a negative pitch means that the bottom row comes first in `buffer`.
+    /// Fails with `Error::InvalidArgument` if the rows do not fit into `pitch` or the bitmap
+    /// does not fit into `buffer`.
+    pub fn new(
+        buffer: &'a mut [u8],
+        width: i32,
+        rows: i32,
+        pitch: i32,
+        pixel_mode: PixelMode,
+    ) -> FtResult<Self> {
+        if width < 0 || rows < 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let row_bytes = pixel_mode
+            .row_bytes(width as usize)
+            .ok_or(Error::InvalidArgument)?;
+        let stride = pitch.unsigned_abs() as usize;
+        if stride < row_bytes {
+            return Err(Error::InvalidArgument);
+        }
+        match stride.checked_mul(rows as usize) {
+            Some(size) if size <= buffer.len() => {}
+            _ => return Err(Error::InvalidArgument),
+        }
+
+        Ok(BitmapMut {
+            raw: ffi::FT_Bitmap {
+                rows,
+                width,
+                pitch,
+                buffer: buffer.as_mut_ptr(),
+                num_grays: pixel_mode.num_grays(),
+                pixel_mode: pixel_mode.to_raw() as _,
+                palette_mode: 0,
+                palette: ptr::null_mut(),
+            },
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Borrow the bitmap for read access, e.g. for `Bitmap::pixel` or `Bitmap::to_rgba`.
+    pub fn as_bitmap(&self) -> BitmapRef<'_> {
+        BitmapRef {
+            view: unsafe { Bitmap::from_raw(&self.raw) },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The number of pixels in bitmap row.
+    pub fn width(&self) -> i32 {
+        self.raw.width
+    }
+
+    /// The number of bitmap rows.
+    pub fn rows(&self) -> i32 {
+        self.raw.rows
+    }
+
+    /// The number of bytes from one row to the next; see `Bitmap::pitch`.
+    pub fn pitch(&self) -> i32 {
+        self.raw.pitch
+    }
+
+    /// The pixel mode, i.e., how pixel bits are stored.
+    pub fn pixel_mode(&self) -> FtResult<PixelMode> {
+        self.as_bitmap().pixel_mode()
+    }
+
+    /// The bitmap's bytes, starting with the first row in memory.
+    pub fn buffer(&self) -> &[u8] {
+        let size = self.raw.pitch.unsigned_abs() as usize * self.raw.rows as usize;
+        unsafe { slice::from_raw_parts(self.raw.buffer, size) }
+    }
+
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        let size = self.raw.pitch.unsigned_abs() as usize * self.raw.rows as usize;
+        unsafe { slice::from_raw_parts_mut(self.raw.buffer, size) }
+    }
+
+    pub fn raw(&self) -> &ffi::FT_Bitmap {
+        &self.raw
+    }
+}
+
+/// A `Bitmap` view of a `BitmapMut`, returned by `BitmapMut::as_bitmap`.
+pub struct BitmapRef<'a> {
+    view: Bitmap,
+    _phantom: PhantomData<&'a ffi::FT_Bitmap>,
+}
+
+impl Deref for BitmapRef<'_> {
+    type Target = Bitmap;
+
+    fn deref(&self) -> &Bitmap {
+        &self.view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mul_u8, AlphaMode, BitmapMut, CoverageTable, OwnedBitmap, Pixel, PixelMode};
+    use crate::face::LoadFlag;
+    use crate::{Color, Library, Vector};
+    use std::path::PathBuf;
+
+    #[test]
+    fn pixels() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        face.set_pixel_sizes(0, 24).unwrap();
+
+        face.load_char(
+            'A' as usize,
+            LoadFlag::RENDER | LoadFlag::MONOCHROME | LoadFlag::TARGET_MONO,
+        )
+        .unwrap();
+        let mono = face.glyph().bitmap().to_owned(&library).unwrap();
+        // Gray with two gray levels.
+        let gray = mono.convert(1).unwrap();
+        assert_eq!(gray.num_grays(), 2);
+        let (width, rows) = mono.pixel_dimensions();
+        assert_eq!(mono.rows_iter().len(), rows);
+        for (y, (mono_row, gray_row)) in mono.rows_iter().zip(gray.rows_iter()).enumerate() {
+            assert_eq!(mono_row.len(), width);
+            for (x, (m, g)) in mono_row.zip(gray_row).enumerate() {
+                assert!(m == Pixel::Coverage(0) || m == Pixel::Coverage(255));
+                assert_eq!(m, g);
+                assert_eq!(m, mono.pixel(x, y));
+            }
+        }
+
+        face.load_char('A' as usize, LoadFlag::RENDER | LoadFlag::TARGET_LCD)
+            .unwrap();
+        let lcd = face.glyph().bitmap();
+        assert_eq!(lcd.pixel_mode(), Ok(PixelMode::Lcd));
+        assert_eq!(
+            lcd.pixel_dimensions(),
+            (lcd.width() as usize / 3, lcd.rows() as usize)
+        );
+        assert!(lcd
+            .rows_iter()
+            .flatten()
+            .all(|p| matches!(p, Pixel::Subpixel { .. })));
+    }
+
+    #[test]
+    fn rgba() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        face.set_pixel_sizes(0, 24).unwrap();
+        face.load_char('A' as usize, LoadFlag::RENDER).unwrap();
+        let gray = face.glyph().bitmap();
+        let (width, rows) = gray.pixel_dimensions();
+
+        let color = Color::new(255, 128, 0, 255);
+        let straight = gray.to_rgba(color, AlphaMode::Straight);
+        let premultiplied = gray.to_rgba(color, AlphaMode::Premultiplied);
+        assert_eq!(straight.len(), width * rows * 4);
+        for ((pixel, straight), premultiplied) in gray
+            .rows_iter()
+            .flatten()
+            .zip(straight.chunks(4))
+            .zip(premultiplied.chunks(4))
+        {
+            let Pixel::Coverage(coverage) = pixel else {
+                panic!("unexpected pixel {:?}", pixel)
+            };
+            assert_eq!(straight[3], coverage);
+            assert_eq!(premultiplied[3], coverage);
+            assert_eq!(premultiplied[0], coverage);
+            if coverage > 0 {
+                assert_eq!(&straight[..3], &[255, 128, 0]);
+            }
+        }
+        let identity = CoverageTable::new(1.0, 0.0);
+        assert_eq!(
+            identity,
+            CoverageTable::from_table(std::array::from_fn(|i| i as u8))
+        );
+        assert_eq!(
+            gray.to_rgba_with_table(color, AlphaMode::Straight, &identity),
+            straight
+        );
+        assert!(CoverageTable::new(1.8, 0.0).apply(128) > 128);
+
+        // One Lcd pixel with full red and half green coverage, drawn in white at half opacity.
+        let mut buffer = [255, 128, 0];
+        let lcd = BitmapMut::new(&mut buffer, 3, 1, 3, PixelMode::Lcd).unwrap();
+        let lcd = lcd.as_bitmap();
+        let white = Color::new(255, 255, 255, 255);
+        let mask = lcd.to_rgba(Color::new(255, 255, 255, 128), AlphaMode::Straight);
+        assert_eq!(mask, [128, 64, 0, 128]);
+        assert_eq!(
+            mask,
+            lcd.to_rgba(Color::new(0, 0, 0, 128), AlphaMode::Premultiplied)
+        );
+        // Dual-source blending white text over a white background leaves it white, and over a
+        // black background gives the scaled per-channel coverage.
+        let blend = |background: u8| [0, 1, 2].map(|i| mask[i] + mul_u8(background, 255 - mask[i]));
+        assert_eq!(blend(255), [255, 255, 255]);
+        assert_eq!(blend(0), [128, 64, 0]);
+
+        // A half transparent red Bgra pixel.
+        let mut buffer = [0, 0, 128, 128];
+        let bgra = BitmapMut::new(&mut buffer, 1, 1, 4, PixelMode::Bgra).unwrap();
+        let bgra = bgra.as_bitmap();
+        assert_eq!(
+            bgra.to_rgba(white, AlphaMode::Premultiplied),
+            [128, 0, 0, 128]
+        );
+        assert_eq!(bgra.to_rgba(white, AlphaMode::Straight), [255, 0, 0, 128]);
+    }
+
+    #[test]
+    fn upwards_flow() {
+        // Two rows of Gray4 pixels, with the bottom row stored first.
+        let mut buffer = [0x0F, 0xF0];
+        let bitmap = BitmapMut::new(&mut buffer, 2, 2, -1, PixelMode::Gray4).unwrap();
+        let bitmap = bitmap.as_bitmap();
+        assert_eq!(bitmap.pixel(0, 0), Pixel::Coverage(255));
+        assert_eq!(bitmap.pixel(1, 0), Pixel::Coverage(0));
+        assert_eq!(bitmap.pixel(0, 1), Pixel::Coverage(0));
+        assert_eq!(bitmap.pixel(1, 1), Pixel::Coverage(255));
+    }
+
+    #[test]
+    fn bgra_pixels() {
+        let mut buffer = [0x00, 0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0xFF, 0, 0, 0, 0];
+        let bitmap = BitmapMut::new(&mut buffer, 3, 1, 12, PixelMode::Bgra).unwrap();
+        let bitmap = bitmap.as_bitmap();
+        assert_eq!(bitmap.pixel(0, 0), Pixel::Color(Color::new(255, 0, 0, 128)));
+        assert_eq!(
+            bitmap.pixel(1, 0),
+            Pixel::Color(Color::new(0x10, 0x20, 0x40, 255))
+        );
+        assert_eq!(bitmap.pixel(2, 0), Pixel::Color(Color::new(0, 0, 0, 0)));
+    }
+
+    #[test]
+    fn owned_bitmap() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        face.set_pixel_sizes(0, 24).unwrap();
+        face.load_char(
+            'A' as usize,
+            LoadFlag::RENDER | LoadFlag::MONOCHROME | LoadFlag::TARGET_MONO,
+        )
+        .unwrap();
+
+        let mono = face.glyph().bitmap().to_owned(&library).unwrap();
+        let bits = mono.buffer().to_vec();
+        face.load_char('B' as usize, LoadFlag::RENDER).unwrap();
+        assert_eq!(mono.pixel_mode(), Ok(PixelMode::Mono));
+        assert_eq!(mono.buffer(), &bits[..]);
+
+        let gray = mono.convert(4).unwrap();
+        assert_eq!(gray.pixel_mode(), Ok(PixelMode::Gray));
+        assert_eq!(gray.num_grays(), 2);
+        assert_eq!(gray.pitch() % 4, 0);
+        assert_eq!((gray.width(), gray.rows()), (mono.width(), mono.rows()));
+        let set_bits: u32 = bits.iter().map(|b| b.count_ones()).sum();
+        let set_pixels = gray.buffer().iter().filter(|&&v| v == 1).count();
+        assert_eq!(set_pixels as u32, set_bits);
+        // Row padding is zeroed as well.
+        assert!(gray.buffer().iter().all(|&v| v <= 1));
+
+        let mut bold = gray.clone();
+        bold.embolden(128, 0).unwrap();
+        assert_eq!(bold.width(), gray.width() + 2);
+        assert_eq!(bold.rows(), gray.rows());
+
+        let mut target = OwnedBitmap::new(&library);
+        let red = Color::new(255, 0, 0, 255);
+        target.blend(&gray, Vector { x: 64, y: 640 }, red).unwrap();
+        assert_eq!(target.pixel_mode(), Ok(PixelMode::Bgra));
+        assert_eq!((target.width(), target.rows()), (gray.width(), gray.rows()));
+        assert_eq!(target.origin(), Vector { x: 64, y: 640 });
+    }
 }
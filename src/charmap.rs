@@ -1,4 +1,26 @@
+use crate::{ffi, ffi_ext};
 use freetype_sys::FT_CharMap;
+use std::marker::PhantomData;
+
+/// The encoding of a charmap, as used by `Face::select_charmap`.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    None = ffi::FT_ENCODING_NONE,
+    MsSymbol = ffi::FT_ENCODING_MS_SYMBOL,
+    Unicode = ffi::FT_ENCODING_UNICODE,
+    Sjis = ffi::FT_ENCODING_SJIS,
+    Gb2312 = ffi::FT_ENCODING_GB2312,
+    Big5 = ffi::FT_ENCODING_BIG5,
+    Wansung = ffi::FT_ENCODING_WANSUNG,
+    Johab = ffi::FT_ENCODING_JOHAB,
+    AdobeStandard = ffi::FT_ENCODING_ADOBE_STANDARD,
+    AdobeExpert = ffi::FT_ENCODING_ADOBE_EXPERT,
+    AdobeCustom = ffi::FT_ENCODING_ADOBE_CUSTOM,
+    AdobeLatin1 = ffi::FT_ENCODING_ADOBE_LATIN_1,
+    OldLatin2 = ffi::FT_ENCODING_OLD_LATIN_2,
+    AppleRoman = ffi::FT_ENCODING_APPLE_ROMAN,
+}
 
 pub struct CharMap {
     raw: FT_CharMap,
@@ -21,7 +43,68 @@ impl CharMap {
         unsafe { (*self.raw).encoding }
     }
 
+    /// The index of this charmap in its face's charmap list.
+    pub fn index(&self) -> usize {
+        unsafe { ffi::FT_Get_Charmap_Index(self.raw) as usize }
+    }
+
+    /// The language ID of a TrueType `cmap` subtable. This is 0 for subtables that are not
+    /// Macintosh-specific, and for charmaps that do not come from a TrueType `cmap` table.
+    pub fn language_id(&self) -> u32 {
+        unsafe { ffi_ext::FT_Get_CMap_Language_ID(self.raw) as u32 }
+    }
+
+    /// The format of a TrueType `cmap` subtable, or `None` if the charmap does not come from a
+    /// TrueType `cmap` table.
+    pub fn format(&self) -> Option<u32> {
+        let format = unsafe { ffi_ext::FT_Get_CMap_Format(self.raw) };
+        if format < 0 {
+            None
+        } else {
+            Some(format as u32)
+        }
+    }
+
     pub fn raw(&self) -> FT_CharMap {
         self.raw
     }
 }
+
+/// An iterator over the charmaps of a face, returned by `Face::charmaps`.
+pub struct CharMaps<'a> {
+    face_raw: ffi::FT_Face,
+    index: isize,
+    count: isize,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> CharMaps<'a> {
+    pub(crate) unsafe fn new(face_raw: ffi::FT_Face) -> Self {
+        CharMaps {
+            face_raw,
+            index: 0,
+            count: (*face_raw).num_charmaps as isize,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for CharMaps<'a> {
+    type Item = CharMap;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let charmap = unsafe { *(*self.face_raw).charmaps.offset(self.index) };
+        self.index += 1;
+        Some(CharMap::new(charmap))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.count - self.index) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for CharMaps<'a> {}
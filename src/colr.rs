@@ -0,0 +1,624 @@
+use crate::{ffi, ffi_ext, Vector};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::null_mut;
+
+/// The palette entry index used by COLR layers that should be drawn in the text foreground
+/// color, see `Face::set_palette_foreground_color`.
+pub const FOREGROUND_COLOR_INDEX: u32 = 0xFFFF;
+
+/// An iterator over the layers of a COLRv0 color glyph, returned by `Face::color_glyph_layers`.
+///
+/// Each item is a `(glyph_index, color_index)` pair, from bottom to top. Load the layer with
+/// `Face::load_glyph` and tint it with entry `color_index` of the selected palette, or with the
+/// foreground color if it equals `FOREGROUND_COLOR_INDEX`.
+pub struct ColorGlyphLayers<'a> {
+    face_raw: ffi::FT_Face,
+    base_glyph: u32,
+    iterator: ffi::FT_LayerIterator,
+    marker: PhantomData<&'a ()>,
+}
+
+impl ColorGlyphLayers<'_> {
+    pub(crate) unsafe fn from_raw(face_raw: ffi::FT_Face, base_glyph: u32) -> Self {
+        ColorGlyphLayers {
+            face_raw,
+            base_glyph,
+            iterator: ffi::FT_LayerIterator {
+                num_layers: 0,
+                layer: 0,
+                p: null_mut(),
+            },
+            marker: PhantomData,
+        }
+    }
+}
+
+impl Iterator for ColorGlyphLayers<'_> {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut glyph_index = 0;
+        let mut color_index = 0;
+        let has_layer = unsafe {
+            ffi::FT_Get_Color_Glyph_Layer(
+                self.face_raw,
+                self.base_glyph,
+                &mut glyph_index,
+                &mut color_index,
+                &mut self.iterator,
+            )
+        };
+        if has_layer {
+            Some((glyph_index, color_index))
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether `Face::color_glyph_paint` should prepend the face's root transform (the scaling set
+/// with `Face::set_char_size` and the matrix set with `Face::set_transform`) to the paint graph.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RootTransform {
+    Include = ffi_ext::FT_COLOR_INCLUDE_ROOT_TRANSFORM,
+    Exclude = ffi_ext::FT_COLOR_NO_ROOT_TRANSFORM,
+}
+
+/// How a color line is extended outside the `[0, 1]` range of its stops.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaintExtend {
+    Pad = ffi_ext::FT_COLR_PAINT_EXTEND_PAD,
+    Repeat = ffi_ext::FT_COLR_PAINT_EXTEND_REPEAT,
+    Reflect = ffi_ext::FT_COLR_PAINT_EXTEND_REFLECT,
+}
+
+/// The compositing and blending modes of `Paint::Composite`.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompositeMode {
+    Clear = ffi_ext::FT_COLR_COMPOSITE_CLEAR,
+    Src = ffi_ext::FT_COLR_COMPOSITE_SRC,
+    Dest = ffi_ext::FT_COLR_COMPOSITE_DEST,
+    SrcOver = ffi_ext::FT_COLR_COMPOSITE_SRC_OVER,
+    DestOver = ffi_ext::FT_COLR_COMPOSITE_DEST_OVER,
+    SrcIn = ffi_ext::FT_COLR_COMPOSITE_SRC_IN,
+    DestIn = ffi_ext::FT_COLR_COMPOSITE_DEST_IN,
+    SrcOut = ffi_ext::FT_COLR_COMPOSITE_SRC_OUT,
+    DestOut = ffi_ext::FT_COLR_COMPOSITE_DEST_OUT,
+    SrcAtop = ffi_ext::FT_COLR_COMPOSITE_SRC_ATOP,
+    DestAtop = ffi_ext::FT_COLR_COMPOSITE_DEST_ATOP,
+    Xor = ffi_ext::FT_COLR_COMPOSITE_XOR,
+    Plus = ffi_ext::FT_COLR_COMPOSITE_PLUS,
+    Screen = ffi_ext::FT_COLR_COMPOSITE_SCREEN,
+    Overlay = ffi_ext::FT_COLR_COMPOSITE_OVERLAY,
+    Darken = ffi_ext::FT_COLR_COMPOSITE_DARKEN,
+    Lighten = ffi_ext::FT_COLR_COMPOSITE_LIGHTEN,
+    ColorDodge = ffi_ext::FT_COLR_COMPOSITE_COLOR_DODGE,
+    ColorBurn = ffi_ext::FT_COLR_COMPOSITE_COLOR_BURN,
+    HardLight = ffi_ext::FT_COLR_COMPOSITE_HARD_LIGHT,
+    SoftLight = ffi_ext::FT_COLR_COMPOSITE_SOFT_LIGHT,
+    Difference = ffi_ext::FT_COLR_COMPOSITE_DIFFERENCE,
+    Exclusion = ffi_ext::FT_COLR_COMPOSITE_EXCLUSION,
+    Multiply = ffi_ext::FT_COLR_COMPOSITE_MULTIPLY,
+    HslHue = ffi_ext::FT_COLR_COMPOSITE_HSL_HUE,
+    HslSaturation = ffi_ext::FT_COLR_COMPOSITE_HSL_SATURATION,
+    HslColor = ffi_ext::FT_COLR_COMPOSITE_HSL_COLOR,
+    HslLuminosity = ffi_ext::FT_COLR_COMPOSITE_HSL_LUMINOSITY,
+}
+
+impl CompositeMode {
+    fn from_raw(mode: ffi_ext::FT_Composite_Mode) -> Option<Self> {
+        use self::CompositeMode::*;
+        Some(match mode {
+            ffi_ext::FT_COLR_COMPOSITE_CLEAR => Clear,
+            ffi_ext::FT_COLR_COMPOSITE_SRC => Src,
+            ffi_ext::FT_COLR_COMPOSITE_DEST => Dest,
+            ffi_ext::FT_COLR_COMPOSITE_SRC_OVER => SrcOver,
+            ffi_ext::FT_COLR_COMPOSITE_DEST_OVER => DestOver,
+            ffi_ext::FT_COLR_COMPOSITE_SRC_IN => SrcIn,
+            ffi_ext::FT_COLR_COMPOSITE_DEST_IN => DestIn,
+            ffi_ext::FT_COLR_COMPOSITE_SRC_OUT => SrcOut,
+            ffi_ext::FT_COLR_COMPOSITE_DEST_OUT => DestOut,
+            ffi_ext::FT_COLR_COMPOSITE_SRC_ATOP => SrcAtop,
+            ffi_ext::FT_COLR_COMPOSITE_DEST_ATOP => DestAtop,
+            ffi_ext::FT_COLR_COMPOSITE_XOR => Xor,
+            ffi_ext::FT_COLR_COMPOSITE_PLUS => Plus,
+            ffi_ext::FT_COLR_COMPOSITE_SCREEN => Screen,
+            ffi_ext::FT_COLR_COMPOSITE_OVERLAY => Overlay,
+            ffi_ext::FT_COLR_COMPOSITE_DARKEN => Darken,
+            ffi_ext::FT_COLR_COMPOSITE_LIGHTEN => Lighten,
+            ffi_ext::FT_COLR_COMPOSITE_COLOR_DODGE => ColorDodge,
+            ffi_ext::FT_COLR_COMPOSITE_COLOR_BURN => ColorBurn,
+            ffi_ext::FT_COLR_COMPOSITE_HARD_LIGHT => HardLight,
+            ffi_ext::FT_COLR_COMPOSITE_SOFT_LIGHT => SoftLight,
+            ffi_ext::FT_COLR_COMPOSITE_DIFFERENCE => Difference,
+            ffi_ext::FT_COLR_COMPOSITE_EXCLUSION => Exclusion,
+            ffi_ext::FT_COLR_COMPOSITE_MULTIPLY => Multiply,
+            ffi_ext::FT_COLR_COMPOSITE_HSL_HUE => HslHue,
+            ffi_ext::FT_COLR_COMPOSITE_HSL_SATURATION => HslSaturation,
+            ffi_ext::FT_COLR_COMPOSITE_HSL_COLOR => HslColor,
+            ffi_ext::FT_COLR_COMPOSITE_HSL_LUMINOSITY => HslLuminosity,
+            _ => return None,
+        })
+    }
+}
+
+/// A reference to a palette entry with an additional alpha multiplier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorIndex {
+    /// The palette entry index, or `FOREGROUND_COLOR_INDEX` for the text foreground color.
+    pub palette_index: u16,
+    /// The alpha multiplier, in 2.14 format.
+    pub alpha: ffi::FT_F2Dot14,
+}
+
+impl From<ffi_ext::FT_ColorIndex> for ColorIndex {
+    fn from(color: ffi_ext::FT_ColorIndex) -> Self {
+        ColorIndex {
+            palette_index: color.palette_index,
+            alpha: color.alpha,
+        }
+    }
+}
+
+/// A single stop of a gradient color line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorStop {
+    /// The stop position along the color line, in 16.16 format. Stops usually lie between 0
+    /// and 1, but variable fonts may move them outside of that range.
+    pub offset: ffi::FT_Fixed,
+    pub color: ColorIndex,
+}
+
+/// The color line of a gradient paint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorLine {
+    pub extend: PaintExtend,
+    pub stops: Vec<ColorStop>,
+}
+
+/// An affine transformation in 16.16 format, mapping `(x, y)` to
+/// `(xx * x + xy * y + dx, yx * x + yy * y + dy)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Affine23 {
+    pub xx: ffi::FT_Fixed,
+    pub xy: ffi::FT_Fixed,
+    pub dx: ffi::FT_Fixed,
+    pub yx: ffi::FT_Fixed,
+    pub yy: ffi::FT_Fixed,
+    pub dy: ffi::FT_Fixed,
+}
+
+/// The clip box of a COLRv1 glyph, as returned by `Face::color_glyph_clip_box`. The corners are
+/// given in 26.6 format and include the face's current transformation, so the box need not be
+/// axis-aligned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClipBox {
+    pub bottom_left: Vector,
+    pub top_left: Vector,
+    pub top_right: Vector,
+    pub bottom_right: Vector,
+}
+
+impl From<ffi_ext::FT_ClipBox> for ClipBox {
+    fn from(clip_box: ffi_ext::FT_ClipBox) -> Self {
+        ClipBox {
+            bottom_left: clip_box.bottom_left,
+            top_left: clip_box.top_left,
+            top_right: clip_box.top_right,
+            bottom_right: clip_box.bottom_right,
+        }
+    }
+}
+
+/// An unresolved node of a COLRv1 paint graph. Pass it to `Face::paint` to get the actual
+/// `Paint`. It is only valid for the face it was obtained from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpaquePaint<'a> {
+    raw: ffi_ext::FT_OpaquePaint,
+    marker: PhantomData<&'a ()>,
+}
+
+impl OpaquePaint<'_> {
+    pub(crate) unsafe fn from_raw(raw: ffi_ext::FT_OpaquePaint) -> Self {
+        OpaquePaint {
+            raw,
+            marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn raw(&self) -> ffi_ext::FT_OpaquePaint {
+        self.raw
+    }
+}
+
+/// A node of a COLRv1 paint graph, as returned by `Face::paint`.
+///
+/// Coordinates and radii are in font units, in 16.16 format. If the graph was obtained with
+/// `RootTransform::Include`, its root is a `Paint::Transform` that maps them to 26.6 pixels at
+/// the current size. Angles are in 16.16 format, with 1.0 representing 180 degrees. Child nodes
+/// are returned unresolved, so that callers can walk the graph lazily and guard against cycles
+/// in malformed fonts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Paint<'a> {
+    /// Paint the given layers, from bottom to top.
+    ColrLayers(Vec<OpaquePaint<'a>>),
+    /// Fill with a solid color.
+    Solid(ColorIndex),
+    /// Fill with a linear gradient. `p2` is the rotation point of the gradient.
+    LinearGradient {
+        color_line: ColorLine,
+        p0: Vector,
+        p1: Vector,
+        p2: Vector,
+    },
+    /// Fill with a gradient between two circles.
+    RadialGradient {
+        color_line: ColorLine,
+        c0: Vector,
+        r0: ffi::FT_Pos,
+        c1: Vector,
+        r1: ffi::FT_Pos,
+    },
+    /// Fill with a sweep (conic) gradient around `center`.
+    Sweep {
+        color_line: ColorLine,
+        center: Vector,
+        start_angle: ffi::FT_Fixed,
+        end_angle: ffi::FT_Fixed,
+    },
+    /// Clip `paint` to the outline of glyph `glyph_index`.
+    Glyph {
+        paint: OpaquePaint<'a>,
+        glyph_index: u32,
+    },
+    /// Paint the COLRv1 glyph `glyph_index`; use `Face::color_glyph_paint` to get its root.
+    ColrGlyph { glyph_index: u32 },
+    /// Apply an affine transformation to `paint`.
+    Transform {
+        paint: OpaquePaint<'a>,
+        affine: Affine23,
+    },
+    /// Translate `paint`.
+    Translate {
+        paint: OpaquePaint<'a>,
+        dx: ffi::FT_Fixed,
+        dy: ffi::FT_Fixed,
+    },
+    /// Scale `paint` around a center point.
+    Scale {
+        paint: OpaquePaint<'a>,
+        scale_x: ffi::FT_Fixed,
+        scale_y: ffi::FT_Fixed,
+        center_x: ffi::FT_Fixed,
+        center_y: ffi::FT_Fixed,
+    },
+    /// Rotate `paint` around a center point.
+    Rotate {
+        paint: OpaquePaint<'a>,
+        angle: ffi::FT_Fixed,
+        center_x: ffi::FT_Fixed,
+        center_y: ffi::FT_Fixed,
+    },
+    /// Skew `paint` around a center point.
+    Skew {
+        paint: OpaquePaint<'a>,
+        x_skew_angle: ffi::FT_Fixed,
+        y_skew_angle: ffi::FT_Fixed,
+        center_x: ffi::FT_Fixed,
+        center_y: ffi::FT_Fixed,
+    },
+    /// Composite `source` onto `backdrop` using `mode`.
+    Composite {
+        source: OpaquePaint<'a>,
+        mode: CompositeMode,
+        backdrop: OpaquePaint<'a>,
+    },
+}
+
+impl<'a> Paint<'a> {
+    pub(crate) unsafe fn from_opaque(
+        library_raw: ffi::FT_Library,
+        face_raw: ffi::FT_Face,
+        opaque: OpaquePaint<'a>,
+    ) -> Option<Self> {
+        if has_wide_color_stops(library_raw) {
+            Self::from_opaque_with::<ffi_ext::FT_ColorStopIterator>(face_raw, opaque)
+        } else {
+            Self::from_opaque_with::<ffi_ext::FT_ColorStopIterator_2_12>(face_raw, opaque)
+        }
+    }
+
+    unsafe fn from_opaque_with<I: ColorStopIterator>(
+        face_raw: ffi::FT_Face,
+        opaque: OpaquePaint<'a>,
+    ) -> Option<Self> {
+        let mut raw: ffi_ext::FT_COLR_Paint<I> = mem::zeroed();
+        let raw_ptr = &mut raw as *mut ffi_ext::FT_COLR_Paint<I> as *mut ffi_ext::FT_COLR_Paint;
+        if ffi_ext::FT_Get_Paint(face_raw, opaque.raw(), raw_ptr) == 0 {
+            return None;
+        }
+
+        let u = &raw.u;
+        Some(match raw.format {
+            ffi_ext::FT_COLR_PAINTFORMAT_COLR_LAYERS => {
+                let layers = &u.colr_layers.layer_iterator;
+                let mut iterator = ffi::FT_LayerIterator {
+                    num_layers: layers.num_layers,
+                    layer: layers.layer,
+                    p: layers.p,
+                };
+                let mut paints = Vec::with_capacity(iterator.num_layers as usize);
+                let mut layer = ffi_ext::FT_OpaquePaint {
+                    p: null_mut(),
+                    insert_root_transform: 0,
+                };
+                while ffi_ext::FT_Get_Paint_Layers(face_raw, &mut iterator, &mut layer) != 0 {
+                    paints.push(OpaquePaint::from_raw(layer));
+                }
+                Paint::ColrLayers(paints)
+            }
+            ffi_ext::FT_COLR_PAINTFORMAT_SOLID => Paint::Solid(u.solid.color.into()),
+            ffi_ext::FT_COLR_PAINTFORMAT_LINEAR_GRADIENT => {
+                let gradient = &u.linear_gradient;
+                Paint::LinearGradient {
+                    color_line: color_line(face_raw, &gradient.colorline)?,
+                    p0: gradient.p0,
+                    p1: gradient.p1,
+                    p2: gradient.p2,
+                }
+            }
+            ffi_ext::FT_COLR_PAINTFORMAT_RADIAL_GRADIENT => {
+                let gradient = &u.radial_gradient;
+                Paint::RadialGradient {
+                    color_line: color_line(face_raw, &gradient.colorline)?,
+                    c0: gradient.c0,
+                    r0: gradient.r0,
+                    c1: gradient.c1,
+                    r1: gradient.r1,
+                }
+            }
+            ffi_ext::FT_COLR_PAINTFORMAT_SWEEP_GRADIENT => {
+                let gradient = &u.sweep_gradient;
+                Paint::Sweep {
+                    color_line: color_line(face_raw, &gradient.colorline)?,
+                    center: gradient.center,
+                    start_angle: gradient.start_angle,
+                    end_angle: gradient.end_angle,
+                }
+            }
+            ffi_ext::FT_COLR_PAINTFORMAT_GLYPH => Paint::Glyph {
+                paint: OpaquePaint::from_raw(u.glyph.paint),
+                glyph_index: u.glyph.glyphID,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_COLR_GLYPH => Paint::ColrGlyph {
+                glyph_index: u.colr_glyph.glyphID,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_TRANSFORM => {
+                let affine = &u.transform.affine;
+                Paint::Transform {
+                    paint: OpaquePaint::from_raw(u.transform.paint),
+                    affine: Affine23 {
+                        xx: affine.xx,
+                        xy: affine.xy,
+                        dx: affine.dx,
+                        yx: affine.yx,
+                        yy: affine.yy,
+                        dy: affine.dy,
+                    },
+                }
+            }
+            ffi_ext::FT_COLR_PAINTFORMAT_TRANSLATE => Paint::Translate {
+                paint: OpaquePaint::from_raw(u.translate.paint),
+                dx: u.translate.dx,
+                dy: u.translate.dy,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_SCALE => Paint::Scale {
+                paint: OpaquePaint::from_raw(u.scale.paint),
+                scale_x: u.scale.scale_x,
+                scale_y: u.scale.scale_y,
+                center_x: u.scale.center_x,
+                center_y: u.scale.center_y,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_ROTATE => Paint::Rotate {
+                paint: OpaquePaint::from_raw(u.rotate.paint),
+                angle: u.rotate.angle,
+                center_x: u.rotate.center_x,
+                center_y: u.rotate.center_y,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_SKEW => Paint::Skew {
+                paint: OpaquePaint::from_raw(u.skew.paint),
+                x_skew_angle: u.skew.x_skew_angle,
+                y_skew_angle: u.skew.y_skew_angle,
+                center_x: u.skew.center_x,
+                center_y: u.skew.center_y,
+            },
+            ffi_ext::FT_COLR_PAINTFORMAT_COMPOSITE => Paint::Composite {
+                source: OpaquePaint::from_raw(u.composite.source_paint),
+                mode: CompositeMode::from_raw(u.composite.composite_mode)?,
+                backdrop: OpaquePaint::from_raw(u.composite.backdrop_paint),
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// Whether the linked FreeType uses the `FT_ColorStop` and `FT_ColorStopIterator` layouts of
+/// version 2.13 and later.
+fn has_wide_color_stops(library_raw: ffi::FT_Library) -> bool {
+    let (mut major, mut minor, mut patch) = (0, 0, 0);
+    unsafe { ffi_ext::FT_Library_Version(library_raw, &mut major, &mut minor, &mut patch) };
+    (major, minor) >= (2, 13)
+}
+
+/// A color stop iterator in the layout of a particular FreeType version.
+trait ColorStopIterator: Copy {
+    fn len(&self) -> usize;
+
+    unsafe fn next_stop(&mut self, face_raw: ffi::FT_Face) -> Option<ColorStop>;
+}
+
+impl ColorStopIterator for ffi_ext::FT_ColorStopIterator {
+    fn len(&self) -> usize {
+        self.num_color_stops as usize
+    }
+
+    unsafe fn next_stop(&mut self, face_raw: ffi::FT_Face) -> Option<ColorStop> {
+        let mut stop = ffi_ext::FT_ColorStop::default();
+        if ffi_ext::FT_Get_Colorline_Stops(face_raw, &mut stop, self) == 0 {
+            return None;
+        }
+        Some(ColorStop {
+            offset: stop.stop_offset,
+            color: stop.color.into(),
+        })
+    }
+}
+
+impl ColorStopIterator for ffi_ext::FT_ColorStopIterator_2_12 {
+    fn len(&self) -> usize {
+        self.num_color_stops as usize
+    }
+
+    unsafe fn next_stop(&mut self, face_raw: ffi::FT_Face) -> Option<ColorStop> {
+        let mut stop = ffi_ext::FT_ColorStop_2_12::default();
+        let found = ffi_ext::FT_Get_Colorline_Stops(
+            face_raw,
+            &mut stop as *mut _ as *mut ffi_ext::FT_ColorStop,
+            self as *mut _ as *mut ffi_ext::FT_ColorStopIterator,
+        );
+        if found == 0 {
+            return None;
+        }
+        Some(ColorStop {
+            // 2.14 to 16.16.
+            offset: stop.stop_offset as ffi::FT_Fixed * 4,
+            color: stop.color.into(),
+        })
+    }
+}
+
+unsafe fn color_line<I: ColorStopIterator>(
+    face_raw: ffi::FT_Face,
+    raw: &ffi_ext::FT_ColorLine<I>,
+) -> Option<ColorLine> {
+    let extend = match raw.extend {
+        ffi_ext::FT_COLR_PAINT_EXTEND_PAD => PaintExtend::Pad,
+        ffi_ext::FT_COLR_PAINT_EXTEND_REPEAT => PaintExtend::Repeat,
+        ffi_ext::FT_COLR_PAINT_EXTEND_REFLECT => PaintExtend::Reflect,
+        _ => return None,
+    };
+    let mut iterator = raw.color_stop_iterator;
+    let mut stops = Vec::with_capacity(iterator.len());
+    while let Some(stop) = iterator.next_stop(face_raw) {
+        stops.push(stop);
+    }
+    Some(ColorLine { extend, stops })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        has_wide_color_stops, ColorIndex, ColorStop, Paint, PaintExtend, RootTransform,
+        FOREGROUND_COLOR_INDEX,
+    };
+    use crate::{Library, Vector};
+    use std::path::PathBuf;
+
+    #[test]
+    fn glyph_layers() {
+        // Glyph 3 ('B') is glyph 1 in palette entry 0, below glyph 4 in palette entry 1 and glyph
+        // 4 in the foreground color.
+        let mut font_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        font_path.push("examples/assets/ColrV1Test.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(font_path, 0).unwrap();
+        let base_glyph = face.get_char_index('B' as usize).unwrap();
+        assert_eq!(base_glyph, 3);
+
+        let layers: Vec<_> = face.color_glyph_layers(base_glyph).collect();
+        assert_eq!(layers, [(1, 0), (4, 1), (4, FOREGROUND_COLOR_INDEX)]);
+        // Layer glyphs and COLRv1 glyphs have no layers of their own.
+        assert_eq!(face.color_glyph_layers(1).next(), None);
+        assert_eq!(face.color_glyph_layers(2).next(), None);
+    }
+
+    #[test]
+    fn paint_graph() {
+        // Glyph 2 is painted as glyph 1 (a square) filled with a reflected linear gradient from
+        // palette entry 0 to palette entry 1 at half opacity, and has a clip box.
+        let mut font_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        font_path.push("examples/assets/ColrV1Test.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(font_path, 0).unwrap();
+        assert!(face.color_glyph_paint(1, RootTransform::Exclude).is_none());
+
+        let Some(root) = face.color_glyph_paint(2, RootTransform::Exclude) else {
+            // Some distribution builds of FreeType 2.12, e.g. Debian 12's, leave out COLRv1
+            // support. Build with the `bundled` feature to run the rest of this test.
+            assert!(!has_wide_color_stops(library.raw()));
+            assert!(face.color_glyph_clip_box(2).is_none());
+            return;
+        };
+        let Some(Paint::Glyph { paint, glyph_index }) = face.paint(root) else {
+            panic!("expected a glyph paint");
+        };
+        assert_eq!(glyph_index, 1);
+        let Some(Paint::LinearGradient {
+            color_line,
+            p0,
+            p1,
+            p2,
+        }) = face.paint(paint)
+        else {
+            panic!("expected a linear gradient");
+        };
+        assert_eq!(color_line.extend, PaintExtend::Reflect);
+        assert_eq!(
+            color_line.stops,
+            [
+                ColorStop {
+                    offset: 0,
+                    color: ColorIndex {
+                        palette_index: 0,
+                        alpha: 0x4000,
+                    },
+                },
+                ColorStop {
+                    offset: 0x10000,
+                    color: ColorIndex {
+                        palette_index: 1,
+                        alpha: 0x2000,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            [p0, p1, p2],
+            [
+                Vector { x: 100 << 16, y: 0 },
+                Vector { x: 600 << 16, y: 0 },
+                Vector {
+                    x: 100 << 16,
+                    y: 500 << 16
+                },
+            ]
+        );
+
+        face.set_pixel_sizes(0, 1000).unwrap();
+        let clip_box = face.color_glyph_clip_box(2).unwrap();
+        assert_eq!(clip_box.bottom_left, Vector { x: 100 * 64, y: 0 });
+        assert_eq!(
+            clip_box.top_right,
+            Vector {
+                x: 600 * 64,
+                y: 500 * 64
+            }
+        );
+    }
+}
@@ -1,10 +1,17 @@
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::iter;
 use std::num::NonZeroU32;
+use std::ops::Range;
+use std::ptr::{self, null_mut};
 use std::rc::Rc;
 
-use crate::charmap::CharMap;
-use crate::{ffi, FtResult, GlyphSlot, Matrix, Vector};
+use crate::charmap::{CharMap, CharMaps, Encoding};
+use crate::colr::{ClipBox, ColorGlyphLayers, OpaquePaint, Paint, RootTransform};
+use crate::palette::{self, Color, PaletteData};
+use crate::sfnt_name::{self, NameId, SfntNames};
+use crate::variation::{MmVar, NamedInstance, VariationAxis};
+use crate::{ffi, ffi_ext, Error, FtResult, GlyphSlot, Matrix, Size, SizeRequest, Vector};
 
 #[repr(u32)]
 #[derive(Copy, Clone)]
@@ -38,6 +45,7 @@ bitflags::bitflags! {
         const TARGET_LCD                 = crate::ffi::FT_LOAD_TARGET_LCD;
         const TARGET_LCD_V               = crate::ffi::FT_LOAD_TARGET_LCD_V;
         const COLOR                      = crate::ffi::FT_LOAD_COLOR;
+        const ADVANCE_FAST_ONLY          = crate::ffi_ext::FT_ADVANCE_FLAG_FAST_ONLY;
     }
 }
 
@@ -49,6 +57,22 @@ bitflags::bitflags! {
     }
 }
 
+/// Per-face overrides of library-wide settings, applied with `Face::set_properties`. Fields
+/// left as `None` are not changed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FaceProperties {
+    /// Whether to darken stems of CFF, Type 1 and auto-hinted glyphs, overriding the
+    /// `no-stem-darkening` module property.
+    pub stem_darkening: Option<bool>,
+    /// The five-tap LCD filter, overriding `Library::set_lcd_filter`. Like
+    /// `Library::set_lcd_filter_weights`, this fails with `Error::UnimplementedFeature` if
+    /// FreeType was built without ClearType-style subpixel rendering.
+    pub lcd_filter_weights: Option<[u8; 5]>,
+    /// The seed for the CFF engine's pseudo-random number generator; a negative value restores
+    /// the module's default seed.
+    pub random_seed: Option<i32>,
+}
+
 pub struct CharIterator<'a, BYTES> {
     started: bool,
     face: &'a Face<BYTES>,
@@ -97,6 +121,46 @@ impl<BYTES> Iterator for CharIterator<'_, BYTES> {
     // TODO: implement size_hint
 }
 
+pub struct GlyphNameIterator<'a, BYTES> {
+    face: &'a Face<BYTES>,
+    gindex: u32,
+    num_glyphs: u32,
+}
+
+impl<'a, BYTES> GlyphNameIterator<'a, BYTES> {
+    fn new(face: &'a Face<BYTES>) -> Self {
+        let num_glyphs = if face.has_glyph_names() {
+            face.num_glyphs() as u32
+        } else {
+            0
+        };
+        GlyphNameIterator {
+            face,
+            gindex: 0,
+            num_glyphs,
+        }
+    }
+}
+
+impl<BYTES> Iterator for GlyphNameIterator<'_, BYTES> {
+    type Item = (u32, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.gindex < self.num_glyphs {
+            let gindex = self.gindex;
+            self.gindex += 1;
+            if let Some(name) = self.face.glyph_name(gindex) {
+                return Some((gindex, name));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.num_glyphs - self.gindex) as usize))
+    }
+}
+
 #[derive(Eq, PartialEq, Hash)]
 pub struct Face<BYTES = Rc<Vec<u8>>> {
     library_raw: ffi::FT_Library,
@@ -198,6 +262,79 @@ impl<BYTES> Face<BYTES> {
         }
     }
 
+    /// Request a nominal size, scaled as described by `request`.
+    pub fn request_size(&self, request: &SizeRequest) -> FtResult<()> {
+        let mut raw = request.to_raw();
+        let err = unsafe { ffi::FT_Request_Size(self.raw, &mut raw) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Override library-wide settings for this face only.
+    pub fn set_properties(&self, properties: FaceProperties) -> FtResult<()> {
+        let mut stem_darkening = properties.stem_darkening.map(|value| value as ffi::FT_Bool);
+        let mut lcd_filter_weights = properties.lcd_filter_weights;
+        let mut random_seed = properties.random_seed;
+
+        let mut params = Vec::with_capacity(3);
+        if let Some(value) = stem_darkening.as_mut() {
+            params.push(ffi::FT_Parameter {
+                tag: ffi_ext::FT_PARAM_TAG_STEM_DARKENING,
+                data: value as *mut _ as ffi::FT_Pointer,
+            });
+        }
+        if let Some(weights) = lcd_filter_weights.as_mut() {
+            params.push(ffi::FT_Parameter {
+                tag: ffi_ext::FT_PARAM_TAG_LCD_FILTER_WEIGHTS,
+                data: weights.as_mut_ptr() as ffi::FT_Pointer,
+            });
+        }
+        if let Some(seed) = random_seed.as_mut() {
+            params.push(ffi::FT_Parameter {
+                tag: ffi_ext::FT_PARAM_TAG_RANDOM_SEED,
+                data: seed as *mut _ as ffi::FT_Pointer,
+            });
+        }
+
+        let err = unsafe {
+            ffi_ext::FT_Face_Properties(self.raw, params.len() as ffi::FT_UInt, params.as_mut_ptr())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Create a new size object for this face. The new size is not active until it is passed to
+    /// `activate_size`.
+    pub fn new_size(&self) -> FtResult<Size<'_>> {
+        let mut size = null_mut();
+        let err = unsafe { ffi_ext::FT_New_Size(self.raw, &mut size) };
+        if err == ffi::FT_Err_Ok {
+            Ok(unsafe { Size::from_raw(size) })
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Make `size` the active size of this face, so that subsequent calls to `set_char_size`,
+    /// `set_pixel_sizes` and `load_glyph` use it.
+    pub fn activate_size(&self, size: &Size) -> FtResult<()> {
+        if size.face_raw() != self.raw {
+            return Err(Error::InvalidSizeHandle);
+        }
+        let err = unsafe { ffi_ext::FT_Activate_Size(size.raw()) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
     pub fn load_glyph(&self, glyph_index: u32, load_flags: LoadFlag) -> FtResult<()> {
         let err = unsafe { ffi::FT_Load_Glyph(self.raw, glyph_index, load_flags.bits()) };
         if err == ffi::FT_Err_Ok {
@@ -217,6 +354,53 @@ impl<BYTES> Face<BYTES> {
         }
     }
 
+    /// Retrieve the advance of a glyph without loading it. The advance is in font units with
+    /// `LoadFlag::NO_SCALE`, and in 16.16 pixels otherwise; it is vertical with
+    /// `LoadFlag::VERTICAL_LAYOUT`. With `LoadFlag::ADVANCE_FAST_ONLY` this fails instead of
+    /// falling back to the full glyph loader when the font has no quick way to get advances.
+    pub fn advance(&self, glyph_index: u32, load_flags: LoadFlag) -> FtResult<ffi::FT_Fixed> {
+        let mut advance = 0;
+        let err = unsafe {
+            ffi_ext::FT_Get_Advance(self.raw, glyph_index, load_flags.bits(), &mut advance)
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(advance)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Like `advance`, but retrieves the advances of all glyphs in `glyph_indices` at once.
+    /// `advances` must be at least as long as the range.
+    pub fn advances(
+        &self,
+        glyph_indices: Range<u32>,
+        load_flags: LoadFlag,
+        advances: &mut [ffi::FT_Fixed],
+    ) -> FtResult<()> {
+        let count = glyph_indices.len();
+        if advances.len() < count {
+            return Err(Error::InvalidArgument);
+        }
+        if count == 0 {
+            return Ok(());
+        }
+        let err = unsafe {
+            ffi_ext::FT_Get_Advances(
+                self.raw,
+                glyph_indices.start,
+                count as ffi::FT_UInt,
+                load_flags.bits(),
+                advances.as_mut_ptr(),
+            )
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
     pub fn set_transform(&self, matrix: &mut Matrix, delta: &mut Vector) {
         unsafe {
             ffi::FT_Set_Transform(self.raw, matrix, delta);
@@ -232,6 +416,66 @@ impl<BYTES> Face<BYTES> {
         }
     }
 
+    /// Retrieve the glyph index of a Unicode variation sequence, i.e. of `charcode` followed by
+    /// `variant_selector` (such as U+FE0F or U+E0100). Returns `None` if the sequence is not
+    /// covered by the face's `cmap` format 14 subtable.
+    pub fn get_char_variant_index(&self, charcode: usize, variant_selector: u32) -> Option<u32> {
+        let res = unsafe {
+            ffi::FT_Face_GetCharVariantIndex(
+                self.raw,
+                charcode as ffi::FT_ULong,
+                variant_selector as ffi::FT_ULong,
+            )
+        };
+        if res == 0 {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Check whether a variation sequence maps to the same glyph as `charcode` alone. Returns
+    /// `None` if the sequence is not covered by the face.
+    pub fn char_variant_is_default(&self, charcode: usize, variant_selector: u32) -> Option<bool> {
+        let res = unsafe {
+            ffi::FT_Face_GetCharVariantIsDefault(
+                self.raw,
+                charcode as ffi::FT_ULong,
+                variant_selector as ffi::FT_ULong,
+            )
+        };
+        match res {
+            -1 => None,
+            res => Some(res == 1),
+        }
+    }
+
+    /// Retrieve all variation selectors supported by the face, in increasing order.
+    pub fn variant_selectors(&self) -> Vec<u32> {
+        unsafe { zero_terminated(ffi::FT_Face_GetVariantSelectors(self.raw)) }
+    }
+
+    /// Retrieve the variation selectors that can follow `charcode`, in increasing order.
+    pub fn variants_of_char(&self, charcode: usize) -> Vec<u32> {
+        unsafe {
+            zero_terminated(ffi::FT_Face_GetVariantsOfChar(
+                self.raw,
+                charcode as ffi::FT_ULong,
+            ))
+        }
+    }
+
+    /// Retrieve the characters that can be followed by `variant_selector`, in increasing order.
+    pub fn chars_of_variant(&self, variant_selector: u32) -> Vec<usize> {
+        let chars = unsafe {
+            zero_terminated(ffi::FT_Face_GetCharsOfVariant(
+                self.raw,
+                variant_selector as ffi::FT_ULong,
+            ))
+        };
+        chars.into_iter().map(|c| c as usize).collect()
+    }
+
     pub fn get_name_index(&self, glyph_name: &str) -> Option<u32> {
         if !unsafe { ffi::FT_HAS_GLYPH_NAMES(self.raw) } {
             return None;
@@ -249,6 +493,50 @@ impl<BYTES> Face<BYTES> {
         CharIterator::new(self)
     }
 
+    /// Retrieve the name of a glyph. Returns `None` if the face has no glyph names or the glyph
+    /// has none.
+    pub fn glyph_name(&self, glyph_index: u32) -> Option<String> {
+        self.glyph_name_with_capacity(glyph_index, 256)
+    }
+
+    fn glyph_name_with_capacity(&self, glyph_index: u32, capacity: usize) -> Option<String> {
+        if !self.has_glyph_names() {
+            return None;
+        }
+
+        let mut buffer = vec![0u8; capacity];
+        loop {
+            let err = unsafe {
+                ffi::FT_Get_Glyph_Name(
+                    self.raw,
+                    glyph_index,
+                    buffer.as_mut_ptr() as ffi::FT_Pointer,
+                    buffer.len() as ffi::FT_UInt,
+                )
+            };
+            if err != ffi::FT_Err_Ok {
+                return None;
+            }
+            let name = CStr::from_bytes_until_nul(&buffer).ok()?;
+            // FreeType silently truncates names that do not fit, so retry with a larger buffer
+            // when the name fills this one.
+            if name.to_bytes().len() + 1 < buffer.len() {
+                let name = name.to_str().ok()?;
+                return if name.is_empty() {
+                    None
+                } else {
+                    Some(name.to_owned())
+                };
+            }
+            buffer = vec![0u8; buffer.len() * 2];
+        }
+    }
+
+    /// Iterate over the `(glyph index, name)` pairs of all named glyphs in the face.
+    pub fn glyph_names(&self) -> GlyphNameIterator<'_, BYTES> {
+        GlyphNameIterator::new(self)
+    }
+
     pub fn get_kerning(
         &self,
         left_char_index: u32,
@@ -273,11 +561,288 @@ impl<BYTES> Face<BYTES> {
         }
     }
 
+    /// Load the contents of the SFNT table with the given tag, e.g.
+    /// `u32::from_be_bytes(*b"GSUB")`. A tag of 0 loads the whole font file. Returns
+    /// `Error::TableMissing` if the face has no such table.
+    pub fn sfnt_table_bytes(&self, tag: u32) -> FtResult<Vec<u8>> {
+        let mut length = 0;
+        let err = unsafe {
+            ffi::FT_Load_Sfnt_Table(self.raw, tag as ffi::FT_ULong, 0, null_mut(), &mut length)
+        };
+        if err != ffi::FT_Err_Ok {
+            return Err(err.into());
+        }
+        let mut buffer = vec![0; length as usize];
+        let err = unsafe {
+            ffi::FT_Load_Sfnt_Table(
+                self.raw,
+                tag as ffi::FT_ULong,
+                0,
+                buffer.as_mut_ptr(),
+                &mut length,
+            )
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(buffer)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// List the `(tag, length)` pairs of all tables in an SFNT-based face, in the order of the
+    /// font's table directory.
+    pub fn sfnt_tables(&self) -> FtResult<Vec<(u32, usize)>> {
+        let mut num_tables = 0;
+        let err = unsafe { ffi_ext::FT_Sfnt_Table_Info(self.raw, 0, null_mut(), &mut num_tables) };
+        if err != ffi::FT_Err_Ok {
+            return Err(err.into());
+        }
+        (0..num_tables as u32)
+            .map(|table_index| {
+                let mut tag = 0;
+                let mut length = 0;
+                let err = unsafe {
+                    ffi_ext::FT_Sfnt_Table_Info(self.raw, table_index, &mut tag, &mut length)
+                };
+                if err == ffi::FT_Err_Ok {
+                    Ok((tag as u32, length as usize))
+                } else {
+                    Err(err.into())
+                }
+            })
+            .collect()
+    }
+
+    /// Iterate over the records of the SFNT `name` table.
+    pub fn sfnt_names(&self) -> SfntNames<'_> {
+        unsafe { SfntNames::from_raw(self.raw) }
+    }
+
+    /// Get the `name` table entry `name_id` in the language closest to the BCP-47 tag
+    /// `language`, falling back to English and then to any decodable record. Typographic and WWS
+    /// family and subfamily names fall back to the entries the OpenType specification prescribes
+    /// when the font lacks them, e.g. `TypographicFamily` to `FontFamily`. Font-specific entries,
+    /// such as axis and palette names, can be found with `sfnt_names`.
+    pub fn localized_name(&self, name_id: NameId, language: &str) -> Option<String> {
+        iter::successors(Some(name_id), |name_id| name_id.fallback()).find_map(|name_id| {
+            sfnt_name::best_localized_name(self.sfnt_names(), name_id as u16, language)
+        })
+    }
+
+    fn mm_var(&self) -> FtResult<MmVar> {
+        let mut raw = null_mut();
+        let err = unsafe { ffi::FT_Get_MM_Var(self.raw, &mut raw) };
+        if err == ffi::FT_Err_Ok {
+            Ok(unsafe { MmVar::from_raw(self.library_raw, raw) })
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Retrieve the design axes of a variable font. Returns an error if the face has no
+    /// variation data.
+    pub fn variation_axes(&self) -> FtResult<Vec<VariationAxis>> {
+        self.mm_var()?.axes()
+    }
+
+    /// Retrieve the named instances of a variable font. If none of them uses the default
+    /// coordinates, FreeType appends an instance for the default design. Returns an error if the
+    /// face has no variation data.
+    pub fn named_instances(&self) -> FtResult<Vec<NamedInstance>> {
+        Ok(self.mm_var()?.named_instances())
+    }
+
+    /// Choose an interpolated font design through design coordinates, given in 16.16 format.
+    /// Axes without a coordinate in `coords` are set to their default value. This affects all
+    /// subsequent calls to `load_glyph` and `load_char`.
+    pub fn set_var_design_coordinates(&self, coords: &[ffi::FT_Fixed]) -> FtResult<()> {
+        let err = unsafe {
+            ffi::FT_Set_Var_Design_Coordinates(self.raw, coords.len() as u32, coords.as_ptr())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Get the design coordinates of the current interpolated font, one per axis.
+    pub fn var_design_coordinates(&self) -> FtResult<Vec<ffi::FT_Fixed>> {
+        let mut coords = vec![0; self.mm_var()?.num_axis()];
+        let err = unsafe {
+            ffi::FT_Get_Var_Design_Coordinates(self.raw, coords.len() as u32, coords.as_mut_ptr())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(coords)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Choose an interpolated font design through normalized coordinates, given in 16.16 format
+    /// and ranging from -1.0 to 1.0. Axes without a coordinate in `coords` are set to their
+    /// default value. This affects all subsequent calls to `load_glyph` and `load_char`.
+    pub fn set_var_blend_coordinates(&self, coords: &[ffi::FT_Fixed]) -> FtResult<()> {
+        let err = unsafe {
+            ffi::FT_Set_Var_Blend_Coordinates(self.raw, coords.len() as u32, coords.as_ptr())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Get the normalized coordinates of the current interpolated font, one per axis.
+    pub fn var_blend_coordinates(&self) -> FtResult<Vec<ffi::FT_Fixed>> {
+        let mut coords = vec![0; self.mm_var()?.num_axis()];
+        let err = unsafe {
+            ffi::FT_Get_Var_Blend_Coordinates(self.raw, coords.len() as u32, coords.as_mut_ptr())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(coords)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Select a named instance of a variable font. `instance_index` is 1-based and refers to the
+    /// entries of `named_instances`; 0 selects the default design.
+    pub fn set_named_instance(&self, instance_index: u32) -> FtResult<()> {
+        let err = unsafe { ffi::FT_Set_Named_Instance(self.raw, instance_index) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    fn raw_palette_data(&self) -> FtResult<ffi_ext::FT_Palette_Data> {
+        let mut data = ffi_ext::FT_Palette_Data {
+            num_palettes: 0,
+            palette_name_ids: ptr::null(),
+            palette_flags: ptr::null(),
+            num_palette_entries: 0,
+            palette_entry_name_ids: ptr::null(),
+        };
+        let err = unsafe { ffi_ext::FT_Palette_Data_Get(self.raw, &mut data) };
+        if err == ffi::FT_Err_Ok {
+            Ok(data)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Retrieve the CPAL palettes of a color font. Faces without a `CPAL` table report no
+    /// palettes.
+    pub fn palette_data(&self) -> FtResult<PaletteData> {
+        let data = self.raw_palette_data()?;
+        Ok(unsafe { PaletteData::from_raw(&data) })
+    }
+
+    /// Activate the palette with index `palette_index` for subsequent glyph loads with
+    /// `LoadFlag::COLOR`, and return its entries.
+    pub fn select_palette(&self, palette_index: u16) -> FtResult<Vec<Color>> {
+        let num_entries = self.raw_palette_data()?.num_palette_entries as usize;
+        let mut entries = null_mut();
+        let err = unsafe { ffi_ext::FT_Palette_Select(self.raw, palette_index, &mut entries) };
+        if err == ffi::FT_Err_Ok {
+            Ok(unsafe { palette::entries_from_raw(entries, num_entries) })
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Set the color used for palette index 0xFFFF, i.e. the text foreground color. The default
+    /// is opaque black.
+    pub fn set_palette_foreground_color(&self, color: Color) -> FtResult<()> {
+        let err = unsafe { ffi_ext::FT_Palette_Set_Foreground_Color(self.raw, color.into()) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Iterate over the COLRv0 layers of `base_glyph`. The iterator is empty if the glyph has no
+    /// color layers.
+    pub fn color_glyph_layers(&self, base_glyph: u32) -> ColorGlyphLayers<'_> {
+        unsafe { ColorGlyphLayers::from_raw(self.raw, base_glyph) }
+    }
+
+    /// Retrieve the root of the COLRv1 paint graph of `base_glyph`, or `None` if the glyph has no
+    /// COLRv1 data.
+    pub fn color_glyph_paint(
+        &self,
+        base_glyph: u32,
+        root_transform: RootTransform,
+    ) -> Option<OpaquePaint<'_>> {
+        let mut paint = ffi_ext::FT_OpaquePaint {
+            p: null_mut(),
+            insert_root_transform: 0,
+        };
+        let found = unsafe {
+            ffi_ext::FT_Get_Color_Glyph_Paint(
+                self.raw,
+                base_glyph,
+                root_transform as u32,
+                &mut paint,
+            )
+        };
+        if found != 0 {
+            Some(unsafe { OpaquePaint::from_raw(paint) })
+        } else {
+            None
+        }
+    }
+
+    /// Resolve a node of a COLRv1 paint graph. Returns `None` if the paint data is invalid or
+    /// uses a format FreeType does not support.
+    pub fn paint<'a>(&'a self, paint: OpaquePaint<'a>) -> Option<Paint<'a>> {
+        unsafe { Paint::from_opaque(self.library_raw, self.raw, paint) }
+    }
+
+    /// Retrieve the clip box of a COLRv1 glyph, transformed by the current size and transform.
+    pub fn color_glyph_clip_box(&self, base_glyph: u32) -> Option<ClipBox> {
+        let mut clip_box = ffi_ext::FT_ClipBox::default();
+        let found =
+            unsafe { ffi_ext::FT_Get_Color_Glyph_ClipBox(self.raw, base_glyph, &mut clip_box) };
+        if found != 0 {
+            Some(clip_box.into())
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve a charmap by index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `charmap_index` is not less than `num_charmaps()`.
     pub fn get_charmap(&self, charmap_index: isize) -> CharMap {
+        assert!(
+            0 <= charmap_index && charmap_index < self.num_charmaps() as isize,
+            "charmap index out of bounds"
+        );
         let charmap = unsafe { *self.raw().charmaps.offset(charmap_index) };
         CharMap::new(charmap)
     }
 
+    /// Iterate over the charmaps of the face.
+    pub fn charmaps(&self) -> CharMaps<'_> {
+        unsafe { CharMaps::new(self.raw) }
+    }
+
+    /// Select the first charmap of the face with the given encoding.
+    pub fn select_charmap(&self, encoding: Encoding) -> FtResult<()> {
+        let err = unsafe { ffi::FT_Select_Charmap(self.raw, encoding as ffi::FT_Encoding) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
     pub fn set_charmap(&self, charmap: &CharMap) -> FtResult<()> {
         let err = unsafe { ffi::FT_Set_Charmap(self.raw, charmap.raw()) };
         if err == ffi::FT_Err_Ok {
@@ -334,6 +899,11 @@ impl<BYTES> Face<BYTES> {
         unsafe { ffi::FT_HAS_GLYPH_NAMES(self.raw) }
     }
 
+    #[inline(always)]
+    pub fn has_multiple_masters(&self) -> bool {
+        unsafe { ffi::FT_HAS_MULTIPLE_MASTERS(self.raw) }
+    }
+
     #[inline(always)]
     pub fn is_cid_keyed(&self) -> bool {
         unsafe { ffi::FT_IS_CID_KEYED(self.raw) }
@@ -466,6 +1036,19 @@ impl<BYTES> Face<BYTES> {
     }
 }
 
+/// Copy a zero-terminated array owned by FreeType, treating null as an empty array.
+unsafe fn zero_terminated(mut ptr: *const u32) -> Vec<u32> {
+    let mut values = Vec::new();
+    if ptr.is_null() {
+        return values;
+    }
+    while *ptr != 0 {
+        values.push(*ptr);
+        ptr = ptr.add(1);
+    }
+    values
+}
+
 impl<BYTES> fmt::Debug for Face<BYTES> {
     fn fmt(&self, form: &mut fmt::Formatter) -> fmt::Result {
         let name = self.style_name().unwrap_or("[unknown name]".to_owned());
@@ -487,3 +1070,251 @@ impl<BYTES> Drop for Face<BYTES> {
         self.bytes = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{FaceProperties, LoadFlag};
+    use crate::charmap::Encoding;
+    use crate::library::Library;
+    use crate::{Color, Error, Palette, PaletteData, PaletteFlag};
+
+    #[test]
+    fn glyph_names() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        let glyph = face.get_char_index('A' as usize).unwrap();
+        assert_eq!(face.glyph_name(glyph).as_deref(), Some("A"));
+        assert_eq!(face.glyph_name(0).as_deref(), Some(".notdef"));
+        // Names longer than the buffer are retried rather than truncated.
+        assert_eq!(
+            face.glyph_name_with_capacity(0, 4).as_deref(),
+            Some(".notdef")
+        );
+        assert_eq!(face.glyph_name(face.num_glyphs() as u32), None);
+
+        let names: Vec<_> = face.glyph_names().collect();
+        assert_eq!(names.len(), face.num_glyphs() as usize);
+        assert_eq!(names[glyph as usize], (glyph, "A".to_owned()));
+        for (index, name) in names {
+            assert_eq!(face.get_name_index(&name), Some(index));
+        }
+    }
+
+    #[test]
+    fn variation_sequences_missing() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        // Fira Sans has no `cmap` format 14 subtable.
+        assert!(face.variant_selectors().is_empty());
+        assert!(face.variants_of_char('A' as usize).is_empty());
+        assert!(face.chars_of_variant(0xFE0F).is_empty());
+        assert_eq!(face.get_char_variant_index('A' as usize, 0xFE0F), None);
+        assert_eq!(face.char_variant_is_default('A' as usize, 0xFE0F), None);
+    }
+
+    #[test]
+    fn charmaps() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        let charmaps: Vec<_> = face.charmaps().collect();
+        assert_eq!(charmaps.len(), face.num_charmaps() as usize);
+        for (i, charmap) in charmaps.iter().enumerate() {
+            assert_eq!(charmap.index(), i);
+        }
+        let formats: Vec<_> = charmaps.iter().map(|c| c.format()).collect();
+        assert_eq!(
+            formats,
+            [Some(4), Some(12), Some(6), Some(4), Some(12)].to_vec()
+        );
+
+        let a = face.get_char_index('A' as usize);
+        face.select_charmap(Encoding::AppleRoman).unwrap();
+        assert_eq!(face.get_char_index(0x41), a);
+        face.select_charmap(Encoding::Unicode).unwrap();
+        assert_eq!(
+            face.select_charmap(Encoding::Big5),
+            Err(Error::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn face_properties() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        face.set_properties(FaceProperties::default()).unwrap();
+        face.set_properties(FaceProperties {
+            stem_darkening: Some(true),
+            lcd_filter_weights: None,
+            random_seed: Some(42),
+        })
+        .unwrap();
+
+        // Custom LCD filters depend on how FreeType was built, see `lcd_settings`.
+        let weights = [0x08, 0x4D, 0x56, 0x4D, 0x08];
+        let result = face.set_properties(FaceProperties {
+            lcd_filter_weights: Some(weights),
+            ..FaceProperties::default()
+        });
+        assert_eq!(result, library.set_lcd_filter_weights(weights));
+
+        face.set_pixel_sizes(0, 16).unwrap();
+        face.load_char('a' as usize, LoadFlag::FORCE_AUTOHINT | LoadFlag::RENDER)
+            .unwrap();
+        assert!(face.glyph().bitmap().width() > 0);
+    }
+
+    #[test]
+    fn advances() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        face.set_pixel_sizes(0, 32).unwrap();
+
+        let glyph = face.get_char_index('A' as usize).unwrap();
+        face.load_glyph(glyph, LoadFlag::NO_SCALE).unwrap();
+        let unscaled = face.advance(glyph, LoadFlag::NO_SCALE).unwrap();
+        assert_eq!(unscaled, face.glyph().metrics().horiAdvance);
+
+        let mut advances = [0; 8];
+        face.advances(glyph..glyph + 8, LoadFlag::NO_SCALE, &mut advances)
+            .unwrap();
+        assert_eq!(advances[0], unscaled);
+        for (i, &advance) in advances.iter().enumerate() {
+            assert_eq!(
+                face.advance(glyph + i as u32, LoadFlag::NO_SCALE),
+                Ok(advance)
+            );
+        }
+
+        assert!(face
+            .advances(0..8, LoadFlag::DEFAULT, &mut advances[..4])
+            .is_err());
+    }
+
+    #[test]
+    fn sfnt_tables() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        let head_tag = u32::from_be_bytes(*b"head");
+
+        let tables = face.sfnt_tables().unwrap();
+        assert!(tables.contains(&(head_tag, 54)));
+
+        let head = face.sfnt_table_bytes(head_tag).unwrap();
+        assert_eq!(head.len(), 54);
+        // The `magicNumber` field of the `head` table.
+        assert_eq!(head[12..16], [0x5F, 0x0F, 0x3C, 0xF5]);
+
+        let missing = face.sfnt_table_bytes(u32::from_be_bytes(*b"ZZZZ"));
+        assert_eq!(missing, Err(crate::Error::TableMissing));
+    }
+
+    #[test]
+    fn variations() {
+        let mut var_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        var_path.push("examples/assets/VarTest.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(var_path, 0).unwrap();
+        assert!(face.has_multiple_masters());
+
+        let axes = face.variation_axes().unwrap();
+        assert_eq!(axes.len(), 1);
+        assert_eq!(axes[0].tag, u32::from_be_bytes(*b"wght"));
+        assert_eq!(
+            (axes[0].minimum, axes[0].default, axes[0].maximum),
+            (100 << 16, 400 << 16, 900 << 16)
+        );
+        assert_eq!(axes[0].name_id, 256);
+        assert!(!axes[0].hidden);
+
+        let instances = face.named_instances().unwrap();
+        let coords: Vec<_> = instances.iter().map(|i| i.coords.clone()).collect();
+        // The font only names Thin and Black; FreeType appends the default instance.
+        assert_eq!(coords, [[100 << 16], [900 << 16], [400 << 16]]);
+        assert_eq!(instances[0].name_id, 257);
+        assert_eq!(instances[0].postscript_name_id, Some(258));
+        assert_eq!(instances[1].postscript_name_id, None);
+        assert_eq!(instances[2].name_id, 2);
+
+        assert_eq!(face.var_design_coordinates(), Ok(vec![400 << 16]));
+        face.set_var_design_coordinates(&[700 << 16]).unwrap();
+        assert_eq!(face.var_design_coordinates(), Ok(vec![700 << 16]));
+        // 700 lies 60% of the way from the default to the maximum.
+        assert_eq!(face.var_blend_coordinates(), Ok(vec![0x999A]));
+        face.set_var_blend_coordinates(&[-0x10000]).unwrap();
+        assert_eq!(face.var_design_coordinates(), Ok(vec![100 << 16]));
+        face.set_named_instance(2).unwrap();
+        assert_eq!(face.var_design_coordinates(), Ok(vec![900 << 16]));
+    }
+
+    #[test]
+    fn variations_missing() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        assert_eq!(face.variation_axes(), Err(Error::InvalidArgument));
+        assert_eq!(face.named_instances(), Err(Error::InvalidArgument));
+        assert_eq!(face.var_design_coordinates(), Err(Error::InvalidArgument));
+    }
+
+    #[test]
+    fn palettes() {
+        let mut colr_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        colr_path.push("examples/assets/ColrV1Test.ttf");
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(colr_path, 0).unwrap();
+
+        let data = face.palette_data().unwrap();
+        assert_eq!(
+            data,
+            PaletteData {
+                palettes: vec![Palette {
+                    name_id: None,
+                    flags: PaletteFlag::empty(),
+                }],
+                entry_name_ids: vec![None, None],
+            }
+        );
+        assert_eq!(
+            face.select_palette(0),
+            Ok(vec![Color::new(255, 0, 0, 255), Color::new(0, 0, 255, 255)])
+        );
+        assert_eq!(face.select_palette(1), Err(Error::InvalidArgument));
+        face.set_palette_foreground_color(Color::new(0, 128, 0, 255))
+            .unwrap();
+
+        let fira = library.new_face(fira_path, 0).unwrap();
+        assert_eq!(fira.palette_data().unwrap().num_palettes(), 0);
+        assert_eq!(fira.select_palette(0), Err(Error::InvalidArgument));
+    }
+}
@@ -0,0 +1,531 @@
+//! Declarations for FreeType APIs that `freetype-sys` does not expose yet.
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use crate::ffi::{
+    FT_Bitmap, FT_Bool, FT_Byte, FT_Char, FT_CharMap, FT_Error, FT_F2Dot14, FT_Face, FT_Fixed,
+    FT_Int, FT_Int32, FT_LayerIterator, FT_Library, FT_Long, FT_Parameter, FT_Pos, FT_Short,
+    FT_Size, FT_UInt, FT_UInt16, FT_ULong, FT_UShort, FT_Vector,
+};
+use libc::{c_int, c_uchar, c_uint, c_void};
+use std::mem::ManuallyDrop;
+
+pub const FT_PALETTE_FOR_LIGHT_BACKGROUND: FT_UShort = 0x01;
+pub const FT_PALETTE_FOR_DARK_BACKGROUND: FT_UShort = 0x02;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FT_Color {
+    pub blue: FT_Byte,
+    pub green: FT_Byte,
+    pub red: FT_Byte,
+    pub alpha: FT_Byte,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_Palette_Data {
+    pub num_palettes: FT_UShort,
+    pub palette_name_ids: *const FT_UShort,
+    pub palette_flags: *const FT_UShort,
+    pub num_palette_entries: FT_UShort,
+    pub palette_entry_name_ids: *const FT_UShort,
+}
+
+extern "C" {
+    pub fn FT_Sfnt_Table_Info(
+        face: FT_Face,
+        table_index: FT_UInt,
+        tag: *mut FT_ULong,
+        length: *mut FT_ULong,
+    ) -> FT_Error;
+
+    pub fn FT_Palette_Data_Get(face: FT_Face, apalette: *mut FT_Palette_Data) -> FT_Error;
+    pub fn FT_Palette_Select(
+        face: FT_Face,
+        palette_index: FT_UShort,
+        apalette: *mut *mut FT_Color,
+    ) -> FT_Error;
+    pub fn FT_Palette_Set_Foreground_Color(face: FT_Face, foreground_color: FT_Color) -> FT_Error;
+}
+
+pub type FT_PaintFormat = c_uint;
+pub const FT_COLR_PAINTFORMAT_COLR_LAYERS: FT_PaintFormat = 1;
+pub const FT_COLR_PAINTFORMAT_SOLID: FT_PaintFormat = 2;
+pub const FT_COLR_PAINTFORMAT_LINEAR_GRADIENT: FT_PaintFormat = 4;
+pub const FT_COLR_PAINTFORMAT_RADIAL_GRADIENT: FT_PaintFormat = 6;
+pub const FT_COLR_PAINTFORMAT_SWEEP_GRADIENT: FT_PaintFormat = 8;
+pub const FT_COLR_PAINTFORMAT_GLYPH: FT_PaintFormat = 10;
+pub const FT_COLR_PAINTFORMAT_COLR_GLYPH: FT_PaintFormat = 11;
+pub const FT_COLR_PAINTFORMAT_TRANSFORM: FT_PaintFormat = 12;
+pub const FT_COLR_PAINTFORMAT_TRANSLATE: FT_PaintFormat = 14;
+pub const FT_COLR_PAINTFORMAT_SCALE: FT_PaintFormat = 16;
+pub const FT_COLR_PAINTFORMAT_ROTATE: FT_PaintFormat = 24;
+pub const FT_COLR_PAINTFORMAT_SKEW: FT_PaintFormat = 28;
+pub const FT_COLR_PAINTFORMAT_COMPOSITE: FT_PaintFormat = 32;
+
+pub type FT_PaintExtend = c_uint;
+pub const FT_COLR_PAINT_EXTEND_PAD: FT_PaintExtend = 0;
+pub const FT_COLR_PAINT_EXTEND_REPEAT: FT_PaintExtend = 1;
+pub const FT_COLR_PAINT_EXTEND_REFLECT: FT_PaintExtend = 2;
+
+pub type FT_Composite_Mode = c_uint;
+pub const FT_COLR_COMPOSITE_CLEAR: FT_Composite_Mode = 0;
+pub const FT_COLR_COMPOSITE_SRC: FT_Composite_Mode = 1;
+pub const FT_COLR_COMPOSITE_DEST: FT_Composite_Mode = 2;
+pub const FT_COLR_COMPOSITE_SRC_OVER: FT_Composite_Mode = 3;
+pub const FT_COLR_COMPOSITE_DEST_OVER: FT_Composite_Mode = 4;
+pub const FT_COLR_COMPOSITE_SRC_IN: FT_Composite_Mode = 5;
+pub const FT_COLR_COMPOSITE_DEST_IN: FT_Composite_Mode = 6;
+pub const FT_COLR_COMPOSITE_SRC_OUT: FT_Composite_Mode = 7;
+pub const FT_COLR_COMPOSITE_DEST_OUT: FT_Composite_Mode = 8;
+pub const FT_COLR_COMPOSITE_SRC_ATOP: FT_Composite_Mode = 9;
+pub const FT_COLR_COMPOSITE_DEST_ATOP: FT_Composite_Mode = 10;
+pub const FT_COLR_COMPOSITE_XOR: FT_Composite_Mode = 11;
+pub const FT_COLR_COMPOSITE_PLUS: FT_Composite_Mode = 12;
+pub const FT_COLR_COMPOSITE_SCREEN: FT_Composite_Mode = 13;
+pub const FT_COLR_COMPOSITE_OVERLAY: FT_Composite_Mode = 14;
+pub const FT_COLR_COMPOSITE_DARKEN: FT_Composite_Mode = 15;
+pub const FT_COLR_COMPOSITE_LIGHTEN: FT_Composite_Mode = 16;
+pub const FT_COLR_COMPOSITE_COLOR_DODGE: FT_Composite_Mode = 17;
+pub const FT_COLR_COMPOSITE_COLOR_BURN: FT_Composite_Mode = 18;
+pub const FT_COLR_COMPOSITE_HARD_LIGHT: FT_Composite_Mode = 19;
+pub const FT_COLR_COMPOSITE_SOFT_LIGHT: FT_Composite_Mode = 20;
+pub const FT_COLR_COMPOSITE_DIFFERENCE: FT_Composite_Mode = 21;
+pub const FT_COLR_COMPOSITE_EXCLUSION: FT_Composite_Mode = 22;
+pub const FT_COLR_COMPOSITE_MULTIPLY: FT_Composite_Mode = 23;
+pub const FT_COLR_COMPOSITE_HSL_HUE: FT_Composite_Mode = 24;
+pub const FT_COLR_COMPOSITE_HSL_SATURATION: FT_Composite_Mode = 25;
+pub const FT_COLR_COMPOSITE_HSL_COLOR: FT_Composite_Mode = 26;
+pub const FT_COLR_COMPOSITE_HSL_LUMINOSITY: FT_Composite_Mode = 27;
+
+pub type FT_Color_Root_Transform = c_uint;
+pub const FT_COLOR_INCLUDE_ROOT_TRANSFORM: FT_Color_Root_Transform = 0;
+pub const FT_COLOR_NO_ROOT_TRANSFORM: FT_Color_Root_Transform = 1;
+
+// FreeType 2.13 added `read_variable` to `FT_ColorStopIterator` and widened
+// `FT_ColorStop::stop_offset`, which changes the layout of every gradient paint. The `_2_12`
+// declarations describe the older layout; see `colr::has_wide_color_stops`.
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_ColorStopIterator {
+    pub num_color_stops: FT_UInt,
+    pub current_color_stop: FT_UInt,
+    pub p: *mut FT_Byte,
+    pub read_variable: FT_Bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_ColorStopIterator_2_12 {
+    pub num_color_stops: FT_UInt,
+    pub current_color_stop: FT_UInt,
+    pub p: *mut FT_Byte,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FT_ColorIndex {
+    pub palette_index: FT_UInt16,
+    pub alpha: FT_F2Dot14,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FT_ColorStop {
+    pub stop_offset: FT_Fixed,
+    pub color: FT_ColorIndex,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FT_ColorStop_2_12 {
+    pub stop_offset: FT_F2Dot14,
+    pub color: FT_ColorIndex,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_ColorLine<I = FT_ColorStopIterator> {
+    pub extend: FT_PaintExtend,
+    pub color_stop_iterator: I,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_Affine23 {
+    pub xx: FT_Fixed,
+    pub xy: FT_Fixed,
+    pub dx: FT_Fixed,
+    pub yx: FT_Fixed,
+    pub yy: FT_Fixed,
+    pub dy: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_OpaquePaint {
+    pub p: *mut FT_Byte,
+    pub insert_root_transform: FT_Bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct FT_PaintColrLayers {
+    pub layer_iterator: FT_LayerIterator,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintSolid {
+    pub color: FT_ColorIndex,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintLinearGradient<I = FT_ColorStopIterator> {
+    pub colorline: FT_ColorLine<I>,
+    pub p0: FT_Vector,
+    pub p1: FT_Vector,
+    pub p2: FT_Vector,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintRadialGradient<I = FT_ColorStopIterator> {
+    pub colorline: FT_ColorLine<I>,
+    pub c0: FT_Vector,
+    pub r0: FT_Pos,
+    pub c1: FT_Vector,
+    pub r1: FT_Pos,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintSweepGradient<I = FT_ColorStopIterator> {
+    pub colorline: FT_ColorLine<I>,
+    pub center: FT_Vector,
+    pub start_angle: FT_Fixed,
+    pub end_angle: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintGlyph {
+    pub paint: FT_OpaquePaint,
+    pub glyphID: FT_UInt,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintColrGlyph {
+    pub glyphID: FT_UInt,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintTransform {
+    pub paint: FT_OpaquePaint,
+    pub affine: FT_Affine23,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintTranslate {
+    pub paint: FT_OpaquePaint,
+    pub dx: FT_Fixed,
+    pub dy: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintScale {
+    pub paint: FT_OpaquePaint,
+    pub scale_x: FT_Fixed,
+    pub scale_y: FT_Fixed,
+    pub center_x: FT_Fixed,
+    pub center_y: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintRotate {
+    pub paint: FT_OpaquePaint,
+    pub angle: FT_Fixed,
+    pub center_x: FT_Fixed,
+    pub center_y: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintSkew {
+    pub paint: FT_OpaquePaint,
+    pub x_skew_angle: FT_Fixed,
+    pub y_skew_angle: FT_Fixed,
+    pub center_x: FT_Fixed,
+    pub center_y: FT_Fixed,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FT_PaintComposite {
+    pub source_paint: FT_OpaquePaint,
+    pub composite_mode: FT_Composite_Mode,
+    pub backdrop_paint: FT_OpaquePaint,
+}
+
+#[repr(C)]
+pub union FT_COLR_Paint_u<I: Copy = FT_ColorStopIterator> {
+    pub colr_layers: ManuallyDrop<FT_PaintColrLayers>,
+    pub glyph: FT_PaintGlyph,
+    pub solid: FT_PaintSolid,
+    pub linear_gradient: FT_PaintLinearGradient<I>,
+    pub radial_gradient: FT_PaintRadialGradient<I>,
+    pub sweep_gradient: FT_PaintSweepGradient<I>,
+    pub transform: FT_PaintTransform,
+    pub translate: FT_PaintTranslate,
+    pub scale: FT_PaintScale,
+    pub rotate: FT_PaintRotate,
+    pub skew: FT_PaintSkew,
+    pub composite: FT_PaintComposite,
+    pub colr_glyph: FT_PaintColrGlyph,
+}
+
+#[repr(C)]
+pub struct FT_COLR_Paint<I: Copy = FT_ColorStopIterator> {
+    pub format: FT_PaintFormat,
+    pub u: FT_COLR_Paint_u<I>,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FT_ClipBox {
+    pub bottom_left: FT_Vector,
+    pub top_left: FT_Vector,
+    pub top_right: FT_Vector,
+    pub bottom_right: FT_Vector,
+}
+
+extern "C" {
+    pub fn FT_Get_Color_Glyph_Paint(
+        face: FT_Face,
+        base_glyph: FT_UInt,
+        root_transform: FT_Color_Root_Transform,
+        paint: *mut FT_OpaquePaint,
+    ) -> FT_Bool;
+    pub fn FT_Get_Color_Glyph_ClipBox(
+        face: FT_Face,
+        base_glyph: FT_UInt,
+        clip_box: *mut FT_ClipBox,
+    ) -> FT_Bool;
+    pub fn FT_Get_Paint_Layers(
+        face: FT_Face,
+        iterator: *mut FT_LayerIterator,
+        paint: *mut FT_OpaquePaint,
+    ) -> FT_Bool;
+    pub fn FT_Get_Colorline_Stops(
+        face: FT_Face,
+        color_stop: *mut FT_ColorStop,
+        iterator: *mut FT_ColorStopIterator,
+    ) -> FT_Bool;
+    pub fn FT_Get_Paint(
+        face: FT_Face,
+        opaque_paint: FT_OpaquePaint,
+        paint: *mut FT_COLR_Paint,
+    ) -> FT_Bool;
+}
+
+extern "C" {
+    pub fn FT_Library_Version(
+        library: FT_Library,
+        amajor: *mut FT_Int,
+        aminor: *mut FT_Int,
+        apatch: *mut FT_Int,
+    );
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TT_Header {
+    pub Table_Version: FT_Fixed,
+    pub Font_Revision: FT_Fixed,
+    pub CheckSum_Adjust: FT_Long,
+    pub Magic_Number: FT_Long,
+    pub Flags: FT_UShort,
+    pub Units_Per_EM: FT_UShort,
+    pub Created: [FT_ULong; 2],
+    pub Modified: [FT_ULong; 2],
+    pub xMin: FT_Short,
+    pub yMin: FT_Short,
+    pub xMax: FT_Short,
+    pub yMax: FT_Short,
+    pub Mac_Style: FT_UShort,
+    pub Lowest_Rec_PPEM: FT_UShort,
+    pub Font_Direction: FT_Short,
+    pub Index_To_Loc_Format: FT_Short,
+    pub Glyph_Data_Format: FT_Short,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TT_HoriHeader {
+    pub Version: FT_Fixed,
+    pub Ascender: FT_Short,
+    pub Descender: FT_Short,
+    pub Line_Gap: FT_Short,
+    pub advance_Width_Max: FT_UShort,
+    pub min_Left_Side_Bearing: FT_Short,
+    pub min_Right_Side_Bearing: FT_Short,
+    pub xMax_Extent: FT_Short,
+    pub caret_Slope_Rise: FT_Short,
+    pub caret_Slope_Run: FT_Short,
+    pub caret_Offset: FT_Short,
+    pub Reserved: [FT_Short; 4],
+    pub metric_Data_Format: FT_Short,
+    pub number_Of_HMetrics: FT_UShort,
+    pub long_metrics: *mut c_void,
+    pub short_metrics: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TT_VertHeader {
+    pub Version: FT_Fixed,
+    pub Ascender: FT_Short,
+    pub Descender: FT_Short,
+    pub Line_Gap: FT_Short,
+    pub advance_Height_Max: FT_UShort,
+    pub min_Top_Side_Bearing: FT_Short,
+    pub min_Bottom_Side_Bearing: FT_Short,
+    pub yMax_Extent: FT_Short,
+    pub caret_Slope_Rise: FT_Short,
+    pub caret_Slope_Run: FT_Short,
+    pub caret_Offset: FT_Short,
+    pub Reserved: [FT_Short; 4],
+    pub metric_Data_Format: FT_Short,
+    pub number_Of_VMetrics: FT_UShort,
+    pub long_metrics: *mut c_void,
+    pub short_metrics: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TT_MaxProfile {
+    pub version: FT_Fixed,
+    pub numGlyphs: FT_UShort,
+    pub maxPoints: FT_UShort,
+    pub maxContours: FT_UShort,
+    pub maxCompositePoints: FT_UShort,
+    pub maxCompositeContours: FT_UShort,
+    pub maxZones: FT_UShort,
+    pub maxTwilightPoints: FT_UShort,
+    pub maxStorage: FT_UShort,
+    pub maxFunctionDefs: FT_UShort,
+    pub maxInstructionDefs: FT_UShort,
+    pub maxStackElements: FT_UShort,
+    pub maxSizeOfInstructions: FT_UShort,
+    pub maxComponentElements: FT_UShort,
+    pub maxComponentDepth: FT_UShort,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TT_PCLT {
+    pub Version: FT_Fixed,
+    pub FontNumber: FT_ULong,
+    pub Pitch: FT_UShort,
+    pub xHeight: FT_UShort,
+    pub Style: FT_UShort,
+    pub TypeFamily: FT_UShort,
+    pub CapHeight: FT_UShort,
+    pub SymbolSet: FT_UShort,
+    pub TypeFace: [FT_Char; 16],
+    pub CharacterComplement: [FT_Char; 8],
+    pub FileName: [FT_Char; 6],
+    pub StrokeWeight: FT_Char,
+    pub WidthType: FT_Char,
+    pub SerifStyle: FT_Byte,
+    pub Reserved: FT_Byte,
+}
+
+extern "C" {
+    pub fn FT_New_Size(face: FT_Face, size: *mut FT_Size) -> FT_Error;
+    pub fn FT_Done_Size(size: FT_Size) -> FT_Error;
+    pub fn FT_Activate_Size(size: FT_Size) -> FT_Error;
+}
+
+pub const FT_ADVANCE_FLAG_FAST_ONLY: FT_Int32 = 0x20000000;
+
+extern "C" {
+    pub fn FT_Get_Advance(
+        face: FT_Face,
+        gindex: FT_UInt,
+        load_flags: FT_Int32,
+        padvance: *mut FT_Fixed,
+    ) -> FT_Error;
+    pub fn FT_Get_Advances(
+        face: FT_Face,
+        start: FT_UInt,
+        count: FT_UInt,
+        load_flags: FT_Int32,
+        padvances: *mut FT_Fixed,
+    ) -> FT_Error;
+}
+
+extern "C" {
+    pub fn FT_Get_CMap_Language_ID(charmap: FT_CharMap) -> FT_ULong;
+    pub fn FT_Get_CMap_Format(charmap: FT_CharMap) -> FT_Long;
+}
+
+pub const FT_PARAM_TAG_STEM_DARKENING: FT_ULong = 0x6461726B;
+pub const FT_PARAM_TAG_LCD_FILTER_WEIGHTS: FT_ULong = 0x6C636466;
+pub const FT_PARAM_TAG_RANDOM_SEED: FT_ULong = 0x73656564;
+
+extern "C" {
+    pub fn FT_Face_Properties(
+        face: FT_Face,
+        num_properties: FT_UInt,
+        properties: *mut FT_Parameter,
+    ) -> FT_Error;
+}
+
+extern "C" {
+    pub fn FT_Library_SetLcdFilterWeights(library: FT_Library, weights: *mut c_uchar) -> FT_Error;
+    pub fn FT_Library_SetLcdGeometry(library: FT_Library, sub: *mut FT_Vector) -> FT_Error;
+}
+
+pub const FT_RASTER_FLAG_AA: c_int = 0x1;
+pub const FT_RASTER_FLAG_DIRECT: c_int = 0x2;
+pub const FT_RASTER_FLAG_CLIP: c_int = 0x4;
+
+extern "C" {
+    pub fn FT_Bitmap_Init(abitmap: *mut FT_Bitmap);
+    pub fn FT_Bitmap_Copy(
+        library: FT_Library,
+        source: *const FT_Bitmap,
+        target: *mut FT_Bitmap,
+    ) -> FT_Error;
+    pub fn FT_Bitmap_Embolden(
+        library: FT_Library,
+        bitmap: *mut FT_Bitmap,
+        xStrength: FT_Pos,
+        yStrength: FT_Pos,
+    ) -> FT_Error;
+    pub fn FT_Bitmap_Convert(
+        library: FT_Library,
+        source: *const FT_Bitmap,
+        target: *mut FT_Bitmap,
+        alignment: FT_Int,
+    ) -> FT_Error;
+    pub fn FT_Bitmap_Blend(
+        library: FT_Library,
+        source: *const FT_Bitmap,
+        source_offset: FT_Vector,
+        target: *mut FT_Bitmap,
+        atarget_offset: *mut FT_Vector,
+        color: FT_Color,
+    ) -> FT_Error;
+    pub fn FT_Bitmap_Done(library: FT_Library, bitmap: *mut FT_Bitmap) -> FT_Error;
+}
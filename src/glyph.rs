@@ -1,6 +1,50 @@
 use crate::{ffi, BBox, BitmapGlyph, FtResult, Matrix, RenderMode, Stroker, Vector};
 use std::ptr::null_mut;
 
+/// How the coordinates of a bounding box are returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BBoxMode {
+    /// Font units for glyphs loaded with `LoadFlag::NO_SCALE`, 26.6 pixels otherwise.
+    Unscaled,
+    /// 26.6 pixels.
+    Subpixels,
+    /// 26.6 pixels, grid-fitted: the minimum is rounded down and the maximum up to whole pixels.
+    Gridfit,
+    /// Whole pixels, truncated.
+    Truncate,
+    /// Whole pixels, grid-fitted.
+    Pixels,
+}
+
+impl BBoxMode {
+    pub fn to_raw(self) -> ffi::FT_Glyph_BBox_Mode {
+        match self {
+            BBoxMode::Unscaled => ffi::FT_GLYPH_BBOX_UNSCALED,
+            BBoxMode::Subpixels => ffi::FT_GLYPH_BBOX_SUBPIXELS,
+            BBoxMode::Gridfit => ffi::FT_GLYPH_BBOX_GRIDFIT,
+            BBoxMode::Truncate => ffi::FT_GLYPH_BBOX_TRUNCATE,
+            BBoxMode::Pixels => ffi::FT_GLYPH_BBOX_PIXELS,
+        }
+    }
+
+    /// Convert a box in 26.6 pixels to this mode, the way `FT_Glyph_Get_CBox` does.
+    pub(crate) fn apply(self, mut bbox: BBox) -> BBox {
+        if self == BBoxMode::Gridfit || self == BBoxMode::Pixels {
+            bbox.xMin &= !63;
+            bbox.yMin &= !63;
+            bbox.xMax = (bbox.xMax + 63) & !63;
+            bbox.yMax = (bbox.yMax + 63) & !63;
+        }
+        if self == BBoxMode::Truncate || self == BBoxMode::Pixels {
+            bbox.xMin >>= 6;
+            bbox.yMin >>= 6;
+            bbox.xMax >>= 6;
+            bbox.yMax >>= 6;
+        }
+        bbox
+    }
+}
+
 /// Represents a retrieved glyph from the library
 ///
 /// Note that when this glyph is dropped, so is the library
@@ -54,6 +98,11 @@ impl Glyph {
         acbox
     }
 
+    /// Like `get_cbox`, but with a typed mode.
+    pub fn cbox(&self, mode: BBoxMode) -> BBox {
+        self.get_cbox(mode.to_raw())
+    }
+
     /// Convert a given glyph object to a bitmap glyph object.
     pub fn to_bitmap(
         &self,
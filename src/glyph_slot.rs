@@ -1,4 +1,5 @@
-use crate::{ffi, Bitmap, FtResult, Glyph, GlyphMetrics, Outline, RenderMode, Vector};
+use crate::outline::OutlineBuf;
+use crate::{ffi, Bitmap, Error, FtResult, Glyph, GlyphMetrics, Outline, RenderMode, Vector};
 use std::ptr::null_mut;
 
 /// A description of a given subglyph returned by `GlyphSlot::get_subglyph_info`
@@ -94,7 +95,7 @@ impl GlyphSlot {
 
     /// In freetype, the `Outline` object is a scalable glyph. This method unpacks a glyph into
     /// this object, or returns `None` if the glyph has no `outline`
-    pub fn outline(&self) -> Option<Outline> {
+    pub fn outline(&self) -> Option<Outline<'_>> {
         let outline = unsafe { &(*self.raw).outline };
         let format = unsafe { (*self.raw).format };
 
@@ -106,6 +107,16 @@ impl GlyphSlot {
         }
     }
 
+    /// Copy the glyph's outline into an owned `OutlineBuf` that can be modified without
+    /// affecting the slot. Fails with `Error::InvalidGlyphFormat` if the glyph has no outline.
+    pub fn outline_buf(&self) -> FtResult<OutlineBuf> {
+        let format = unsafe { (*self.raw).format };
+        if format != ffi::FT_GLYPH_FORMAT_OUTLINE {
+            return Err(Error::InvalidGlyphFormat);
+        }
+        unsafe { OutlineBuf::copy_raw(self.library_raw, &(*self.raw).outline) }
+    }
+
     /// This field is used as a bitmap descriptor when the slot format is FT_GLYPH_FORMAT_BITMAP.
     /// Note that the address and content of the bitmap buffer can change between calls of
     /// FT_Load_Glyph and a few other functions.
@@ -40,33 +40,52 @@
 
 pub use freetype_sys;
 
-pub use crate::bitmap::Bitmap;
+pub use crate::bitmap::{
+    AlphaMode, Bitmap, BitmapMut, BitmapRef, CoverageTable, OwnedBitmap, Pixel,
+};
 pub use crate::bitmap_glyph::BitmapGlyph;
 pub use crate::error::{Error, FtResult};
 pub use crate::face::Face;
-pub use crate::glyph::Glyph;
+pub use crate::glyph::{BBoxMode, Glyph};
 pub use crate::glyph_slot::GlyphSlot;
 pub use crate::library::{LcdFilter, Library};
-pub use crate::outline::Outline;
+pub use crate::outline::{Outline, OutlineBuf, OutlineVisitor};
+pub use crate::palette::{Color, Palette, PaletteData, PaletteFlag};
+pub use crate::property::Property;
 pub use crate::render_mode::RenderMode;
+pub use crate::size::{Size, SizeRequest, SizeRequestType};
 pub use crate::stroker::{Stroker, StrokerLineCap, StrokerLineJoin};
+pub use crate::variation::{NamedInstance, VariationAxis};
 pub use freetype_sys as ffi;
 
 pub mod bitmap;
 pub mod bitmap_glyph;
 pub mod charmap;
+pub mod colr;
 pub mod error;
 pub mod face;
+mod ffi_ext;
 pub mod glyph;
 pub mod glyph_slot;
 pub mod library;
 pub mod outline;
+pub mod palette;
+pub mod property;
 pub mod render_mode;
+pub mod sfnt_name;
+pub mod size;
 pub mod stroker;
+pub mod tt_header;
+pub mod tt_hhea;
+pub mod tt_maxp;
 pub mod tt_os2;
+pub mod tt_pclt;
 pub mod tt_postscript;
+pub mod tt_vhea;
+pub mod variation;
 
 pub type BBox = ffi::FT_BBox;
 pub type GlyphMetrics = ffi::FT_Glyph_Metrics;
 pub type Matrix = ffi::FT_Matrix;
+pub type Span = ffi::FT_Span;
 pub type Vector = ffi::FT_Vector;
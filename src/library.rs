@@ -1,9 +1,13 @@
-use crate::ffi;
-use crate::{Error, Face, FtResult, Stroker};
-use libc::{self, c_long, c_void, size_t};
+use crate::{ffi, ffi_ext};
+use crate::{Error, Face, FtResult, Property, Stroker, Vector};
+use libc::{self, c_long, c_uchar, c_ulong, c_void, size_t};
+use std::any::Any;
 use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::ffi::{CString, OsStr};
-use std::ptr::null_mut;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::{self, addr_of_mut, null_mut};
 use std::rc::Rc;
 
 extern "C" fn alloc_library(_memory: ffi::FT_Memory, size: c_long) -> *mut c_void {
@@ -23,6 +27,80 @@ extern "C" fn realloc_library(
     unsafe { libc::realloc(block, new_size as size_t) }
 }
 
+type PanicSlot = Rc<RefCell<Option<Box<dyn Any + Send>>>>;
+
+/// An `FT_StreamRec` followed by the reader it pulls from. The record must come first so that
+/// the `FT_Stream` handed to the callbacks can be cast back to the whole state.
+#[repr(C)]
+struct StreamState<R> {
+    rec: ffi::FT_StreamRec,
+    reader: R,
+    // Shared with `new_stream_face`, since FreeType frees the state when opening fails.
+    panic: PanicSlot,
+}
+
+// `c_ulong` is only 32 bits wide on some targets.
+#[allow(clippy::unnecessary_cast)]
+fn read_reader<R: Read + Seek>(
+    reader: &mut R,
+    offset: c_ulong,
+    buffer: *mut c_uchar,
+    count: c_ulong,
+) -> c_ulong {
+    let seeked = reader.seek(SeekFrom::Start(offset as u64)).is_ok();
+    // A zero `count` is a plain seek request, for which FreeType expects 0 on success.
+    if count == 0 {
+        return if seeked { 0 } else { 1 };
+    }
+    if !seeked {
+        return 0;
+    }
+    let buf = unsafe { std::slice::from_raw_parts_mut(buffer, count as usize) };
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        }
+    }
+    filled as c_ulong
+}
+
+extern "C" fn read_stream<R: Read + Seek>(
+    stream: ffi::FT_Stream,
+    offset: c_ulong,
+    buffer: *mut c_uchar,
+    count: c_ulong,
+) -> c_ulong {
+    let state = unsafe { &mut *(stream as *mut StreamState<R>) };
+    // Report a failed read or seek once the reader has panicked.
+    let failed = if count == 0 { 1 } else { 0 };
+    if (*state.panic).borrow().is_some() {
+        return failed;
+    }
+    // Unwinding through FreeType is not allowed, so carry the panic over to `new_stream_face`.
+    let reader = &mut state.reader;
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        read_reader(reader, offset, buffer, count)
+    })) {
+        Ok(read) => read,
+        Err(payload) => {
+            *state.panic.borrow_mut() = Some(payload);
+            failed
+        }
+    }
+}
+
+extern "C" fn close_stream<R: Read + Seek>(stream: ffi::FT_Stream) {
+    let state = unsafe { Box::from_raw(stream as *mut StreamState<R>) };
+    let slot = state.panic.clone();
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| drop(state))) {
+        slot.borrow_mut().get_or_insert(payload);
+    }
+}
+
 #[repr(u32)]
 #[derive(Copy, Clone)]
 pub enum LcdFilter {
@@ -33,7 +111,7 @@ pub enum LcdFilter {
 }
 
 static mut MEMORY: ffi::FT_MemoryRec = ffi::FT_MemoryRec {
-    user: 0 as *mut c_void,
+    user: null_mut(),
     alloc: alloc_library,
     free: free_library,
     realloc: realloc_library,
@@ -50,7 +128,7 @@ impl Library {
     pub fn init() -> FtResult<Self> {
         let mut raw = null_mut();
 
-        let err = unsafe { ffi::FT_New_Library(&mut MEMORY, &mut raw) };
+        let err = unsafe { ffi::FT_New_Library(addr_of_mut!(MEMORY), &mut raw) };
         if err == ffi::FT_Err_Ok {
             unsafe {
                 ffi::FT_Add_Default_Modules(raw);
@@ -149,6 +227,65 @@ impl Library {
         }
     }
 
+    /// Similar to `new_face`, but pulls file data on demand from `reader` instead of loading it
+    /// all at once. The reader is owned by the face and dropped together with it.
+    ///
+    /// If the reader panics while the face is being opened, the panic is resumed from this
+    /// function. A panic in a later read, for example while loading a glyph, cannot be passed on
+    /// and makes that and every following read fail instead. A panic while dropping the reader
+    /// together with the face is discarded.
+    pub fn new_stream_face<R>(&self, mut reader: R, face_index: isize) -> FtResult<Face>
+    where
+        R: Read + Seek + 'static,
+    {
+        let size = reader
+            .seek(SeekFrom::End(0))
+            .map_err(|_| Error::CannotOpenStream)?;
+        let state = Box::new(StreamState {
+            rec: ffi::FT_StreamRec {
+                base: null_mut(),
+                size: size as c_ulong,
+                pos: 0,
+                descriptor: null_mut(),
+                pathname: null_mut(),
+                read: read_stream::<R>,
+                close: close_stream::<R>,
+                memory: null_mut(),
+                cursor: null_mut(),
+                limit: null_mut(),
+            },
+            reader,
+            panic: PanicSlot::default(),
+        });
+        let panic = state.panic.clone();
+        let args = ffi::FT_Open_Args {
+            flags: ffi::FT_OPEN_STREAM,
+            memory_base: ptr::null(),
+            memory_size: 0,
+            pathname: null_mut(),
+            // FreeType calls the close callback, which frees the state, even when opening fails.
+            stream: Box::into_raw(state) as ffi::FT_Stream,
+            driver: null_mut(),
+            num_params: 0,
+            params: null_mut(),
+        };
+        let mut face = null_mut();
+
+        let err =
+            unsafe { ffi::FT_Open_Face(self.raw, &args, face_index as ffi::FT_Long, &mut face) };
+        let face = if err == ffi::FT_Err_Ok {
+            Ok(unsafe { Face::from_raw(self.raw, face, None) })
+        } else {
+            Err(err.into())
+        };
+        let payload = panic.borrow_mut().take();
+        if let Some(payload) = payload {
+            drop(face);
+            panic::resume_unwind(payload);
+        }
+        face
+    }
+
     pub fn set_lcd_filter(&self, lcd_filter: LcdFilter) -> FtResult<()> {
         let err = unsafe { ffi::FT_Library_SetLcdFilter(self.raw, lcd_filter as u32) };
         if err == ffi::FT_Err_Ok {
@@ -158,6 +295,46 @@ impl Library {
         }
     }
 
+    /// Apply a custom five-tap LCD filter instead of one of the `LcdFilter` presets. The weights
+    /// should add up to about 256. This fails with `Error::UnimplementedFeature` if FreeType was
+    /// built without ClearType-style subpixel rendering.
+    pub fn set_lcd_filter_weights(&self, weights: [u8; 5]) -> FtResult<()> {
+        let mut weights = weights;
+        let err =
+            unsafe { ffi_ext::FT_Library_SetLcdFilterWeights(self.raw, weights.as_mut_ptr()) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Set the positions of the red, green and blue subpixels relative to the pixel center, in
+    /// 1/64 pixel units, for example to handle BGR or vertical-stripe panels. This fails with
+    /// `Error::UnimplementedFeature` if FreeType was built with ClearType-style subpixel
+    /// rendering, which does not support custom geometry.
+    pub fn set_lcd_geometry(&self, subpixels: [Vector; 3]) -> FtResult<()> {
+        let mut subpixels = subpixels;
+        let err = unsafe { ffi_ext::FT_Library_SetLcdGeometry(self.raw, subpixels.as_mut_ptr()) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Set a property of one of the library's modules. Faces that have already been loaded may
+    /// not pick up the new value.
+    pub fn set_property(&self, property: &Property) -> FtResult<()> {
+        property.set(self.raw)
+    }
+
+    /// Read the current value of a module property into `property`. Only the value is
+    /// overwritten; the module (and face, for `increase-x-height`) selects what is read.
+    pub fn get_property(&self, property: &mut Property) -> FtResult<()> {
+        property.get(self.raw)
+    }
+
     /// Get the underlying library object
     pub fn raw(&self) -> ffi::FT_Library {
         self.raw
@@ -175,3 +352,139 @@ impl Drop for Library {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::face::LoadFlag;
+    use std::fs::File;
+    use std::io::{BufReader, Cursor};
+    use std::path::PathBuf;
+
+    #[test]
+    fn properties() {
+        use crate::property::{HintingEngine, IncreaseXHeight, InterpreterVersion, Module, Script};
+
+        let library = Library::init().unwrap();
+
+        let version = Property::InterpreterVersion(InterpreterVersion::V35);
+        library.set_property(&version).unwrap();
+        let mut read = Property::InterpreterVersion(InterpreterVersion::V40);
+        library.get_property(&mut read).unwrap();
+        assert_eq!(read, version);
+
+        for module in [Module::Cff, Module::Type1, Module::T1Cid] {
+            // The FreeType engines are only available in builds with the old engines enabled.
+            let engine = Property::HintingEngine(module, HintingEngine::Adobe);
+            library.set_property(&engine).unwrap();
+            let mut read = Property::HintingEngine(module, HintingEngine::FreeType);
+            library.get_property(&mut read).unwrap();
+            assert_eq!(read, engine);
+        }
+        assert!(library
+            .set_property(&Property::HintingEngine(
+                Module::Autofitter,
+                HintingEngine::Adobe
+            ))
+            .is_err());
+
+        let darkening = Property::NoStemDarkening(Module::Autofitter, false);
+        library.set_property(&darkening).unwrap();
+        let mut read = Property::NoStemDarkening(Module::Autofitter, true);
+        library.get_property(&mut read).unwrap();
+        assert_eq!(read, darkening);
+
+        let params =
+            Property::DarkeningParameters(Module::Cff, [500, 400, 1000, 300, 1667, 200, 2333, 0]);
+        library.set_property(&params).unwrap();
+        let mut read = Property::DarkeningParameters(Module::Cff, [0; 8]);
+        library.get_property(&mut read).unwrap();
+        assert_eq!(read, params);
+
+        let mut script = Property::DefaultScript(Script::None);
+        library.get_property(&mut script).unwrap();
+        assert_eq!(script, Property::DefaultScript(Script::Latin));
+        for script in [Script::Cyrillic, Script::Han] {
+            library
+                .set_property(&Property::DefaultScript(script))
+                .unwrap();
+            let mut read = Property::DefaultScript(Script::None);
+            library.get_property(&mut read).unwrap();
+            assert_eq!(read, Property::DefaultScript(script));
+        }
+        let fallback = Property::FallbackScript(Script::None);
+        library.set_property(&fallback).unwrap();
+        let mut read = Property::FallbackScript(Script::Latin);
+        library.get_property(&mut read).unwrap();
+        assert_eq!(read, fallback);
+
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("examples/assets/FiraSans-Regular.ttf");
+        let face = library.new_face(path, 0).unwrap();
+        let increase = Property::IncreaseXHeight(IncreaseXHeight::new(&face, 14));
+        library.set_property(&increase).unwrap();
+        let mut read = Property::IncreaseXHeight(IncreaseXHeight::new(&face, 0));
+        library.get_property(&mut read).unwrap();
+        assert_eq!(read, increase);
+    }
+
+    #[test]
+    fn lcd_settings() {
+        let library = Library::init().unwrap();
+
+        // Exactly one of the two is supported, depending on how FreeType was built.
+        let weights = library.set_lcd_filter_weights([0x10, 0x40, 0x70, 0x40, 0x10]);
+        let geometry = library.set_lcd_geometry([
+            Vector { x: 21, y: 0 },
+            Vector { x: 0, y: 0 },
+            Vector { x: -21, y: 0 },
+        ]);
+        match (weights, geometry) {
+            (Ok(()), Err(err)) | (Err(err), Ok(())) => {
+                assert_eq!(err, Error::UnimplementedFeature)
+            }
+            results => panic!("unexpected results {:?}", results),
+        }
+    }
+
+    #[test]
+    fn stream_face() {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("examples/assets/FiraSans-Regular.ttf");
+        let library = Library::init().unwrap();
+
+        let reader = BufReader::new(File::open(&path).unwrap());
+        let face = library.new_stream_face(reader, 0).unwrap();
+        assert_eq!(face.num_glyphs(), 1334);
+        face.set_char_size(40 * 64, 0, 50, 0).unwrap();
+        face.load_char('A' as usize, LoadFlag::RENDER).unwrap();
+        assert!(face.glyph().bitmap().width() > 0);
+
+        let garbage = Cursor::new(vec![0u8; 64]);
+        assert!(library.new_stream_face(garbage, 0).is_err());
+    }
+
+    #[test]
+    fn stream_face_propagates_panics() {
+        struct PanickingReader;
+
+        impl Read for PanickingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                panic!("read failed");
+            }
+        }
+
+        impl Seek for PanickingReader {
+            fn seek(&mut self, _: SeekFrom) -> std::io::Result<u64> {
+                Ok(1024)
+            }
+        }
+
+        let library = Library::init().unwrap();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _ = library.new_stream_face(PanickingReader, 0);
+        }));
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"read failed"));
+    }
+}
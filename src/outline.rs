@@ -1,6 +1,11 @@
-use crate::{ffi, Vector};
-use libc::{c_char, c_short};
+use crate::bitmap::{BitmapMut, PixelMode};
+use crate::{ffi, ffi_ext, BBox, BBoxMode, Error, FtResult, Library, Matrix, Span, Vector};
+use libc::{c_char, c_int, c_short, c_void};
+use std::any::Any;
 use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
 use std::slice;
 
 #[derive(Copy, Clone)]
@@ -10,6 +15,93 @@ pub enum Curve {
     Bezier3(Vector, Vector, Vector),
 }
 
+/// Receives the segments of an outline from `Outline::decompose`, in drawing order.
+///
+/// Every contour starts with `move_to` and is explicitly closed by a final segment ending at its
+/// first point.
+pub trait OutlineVisitor {
+    fn move_to(&mut self, to: Vector);
+    fn line_to(&mut self, to: Vector);
+    fn conic_to(&mut self, control: Vector, to: Vector);
+    fn cubic_to(&mut self, control1: Vector, control2: Vector, to: Vector);
+}
+
+struct DecomposeContext<'v, V> {
+    visitor: &'v mut V,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+fn visit<V: OutlineVisitor>(user: *mut c_void, f: impl FnOnce(&mut V)) -> c_int {
+    let context = unsafe { &mut *(user as *mut DecomposeContext<V>) };
+    if context.panic.is_some() {
+        return 1;
+    }
+    // Unwinding through FreeType is not allowed, so carry the panic over to `decompose`.
+    match panic::catch_unwind(AssertUnwindSafe(|| f(context.visitor))) {
+        Ok(()) => 0,
+        Err(payload) => {
+            context.panic = Some(payload);
+            1
+        }
+    }
+}
+
+extern "C" fn move_to<V: OutlineVisitor>(to: *const Vector, user: *mut c_void) -> c_int {
+    visit::<V>(user, |visitor| visitor.move_to(unsafe { *to }))
+}
+
+extern "C" fn line_to<V: OutlineVisitor>(to: *const Vector, user: *mut c_void) -> c_int {
+    visit::<V>(user, |visitor| visitor.line_to(unsafe { *to }))
+}
+
+extern "C" fn conic_to<V: OutlineVisitor>(
+    control: *const Vector,
+    to: *const Vector,
+    user: *mut c_void,
+) -> c_int {
+    visit::<V>(user, |visitor| unsafe { visitor.conic_to(*control, *to) })
+}
+
+extern "C" fn cubic_to<V: OutlineVisitor>(
+    control1: *const Vector,
+    control2: *const Vector,
+    to: *const Vector,
+    user: *mut c_void,
+) -> c_int {
+    visit::<V>(user, |visitor| unsafe {
+        visitor.cubic_to(*control1, *control2, *to)
+    })
+}
+
+struct SpanContext<'f, F> {
+    callback: &'f mut F,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+extern "C" fn gray_spans<F: FnMut(i32, &[Span])>(
+    y: c_int,
+    count: c_int,
+    spans: *const Span,
+    user: *mut c_void,
+) {
+    let context = unsafe { &mut *(user as *mut SpanContext<F>) };
+    if context.panic.is_some() || count <= 0 {
+        return;
+    }
+    let spans = unsafe { slice::from_raw_parts(spans, count as usize) };
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| (context.callback)(y, spans))) {
+        context.panic = Some(payload);
+    }
+}
+
+extern "C" fn unused_spans(_: c_int, _: c_int, _: *const Span, _: *mut c_void) {}
+
+extern "C" fn unused_bit_test(_: c_int, _: c_int, _: *mut c_void) -> c_int {
+    0
+}
+
+extern "C" fn unused_bit_set(_: c_int, _: c_int, _: *mut c_void) {}
+
 pub struct Outline<'a> {
     raw: &'a ffi::FT_Outline,
 }
@@ -20,14 +112,23 @@ impl<'a> Outline<'a> {
     }
 
     pub fn points(&self) -> &'a [Vector] {
+        if self.raw.n_points == 0 {
+            return &[];
+        }
         unsafe { slice::from_raw_parts(self.raw.points, self.raw.n_points as usize) }
     }
 
     pub fn tags(&self) -> &'a [c_char] {
+        if self.raw.n_points == 0 {
+            return &[];
+        }
         unsafe { slice::from_raw_parts(self.raw.tags, self.raw.n_points as usize) }
     }
 
     pub fn contours(&self) -> &'a [c_short] {
+        if self.raw.n_contours == 0 {
+            return &[];
+        }
         unsafe { slice::from_raw_parts(self.raw.contours, self.raw.n_contours as usize) }
     }
 
@@ -38,6 +139,333 @@ impl<'a> Outline<'a> {
     pub fn flags(&self) -> i32 {
         self.raw.flags
     }
+
+    /// Return the outline's control box, which encloses all points including Bézier control
+    /// points. This is fast, but can be larger than the exact bounding box.
+    pub fn cbox(&self, mode: BBoxMode) -> BBox {
+        let mut cbox = BBox {
+            xMin: 0,
+            yMin: 0,
+            xMax: 0,
+            yMax: 0,
+        };
+        unsafe { ffi::FT_Outline_Get_CBox(self.raw, &mut cbox) };
+        mode.apply(cbox)
+    }
+
+    /// Compute the exact bounding box of the outline, taking the extrema of its Bézier arcs
+    /// into account.
+    pub fn exact_bbox(&self, mode: BBoxMode) -> FtResult<BBox> {
+        let mut bbox = BBox {
+            xMin: 0,
+            yMin: 0,
+            xMax: 0,
+            yMax: 0,
+        };
+        let err = unsafe { ffi::FT_Outline_Get_BBox(self.raw, &mut bbox) };
+        if err == ffi::FT_Err_Ok {
+            Ok(mode.apply(bbox))
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Walk the outline's contours with FreeType's decomposer, calling `visitor` for every
+    /// segment. Each point is transformed to `(x << shift) - delta` (likewise for y) before it
+    /// is passed on.
+    pub fn decompose<V: OutlineVisitor>(
+        &self,
+        visitor: &mut V,
+        shift: i32,
+        delta: ffi::FT_Pos,
+    ) -> FtResult<()> {
+        let funcs = ffi::FT_Outline_Funcs {
+            move_to: move_to::<V>,
+            line_to: line_to::<V>,
+            conic_to: conic_to::<V>,
+            cubic_to: cubic_to::<V>,
+            shift,
+            delta,
+        };
+        let mut context = DecomposeContext {
+            visitor,
+            panic: None,
+        };
+        let err = unsafe {
+            ffi::FT_Outline_Decompose(
+                self.raw as *const _ as *mut _,
+                &funcs,
+                &mut context as *mut DecomposeContext<V> as *mut c_void,
+            )
+        };
+        if let Some(payload) = context.panic {
+            panic::resume_unwind(payload);
+        }
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Rasterize the outline with anti-aliasing, passing the coverage of each scanline to
+    /// `callback` instead of drawing into a bitmap. The callback receives the scanline's y
+    /// coordinate, which grows upwards, and its spans in increasing x order. Only pixels inside
+    /// `clip_box`, in whole pixels, are rendered if it is given.
+    pub fn render_spans<F>(
+        &self,
+        library: &Library,
+        clip_box: Option<BBox>,
+        callback: &mut F,
+    ) -> FtResult<()>
+    where
+        F: FnMut(i32, &[Span]),
+    {
+        let mut context = SpanContext {
+            callback,
+            panic: None,
+        };
+        let mut flags = ffi_ext::FT_RASTER_FLAG_AA | ffi_ext::FT_RASTER_FLAG_DIRECT;
+        if clip_box.is_some() {
+            flags |= ffi_ext::FT_RASTER_FLAG_CLIP;
+        }
+        let mut params = ffi::FT_Raster_Params {
+            target: ptr::null(),
+            source: ptr::null(),
+            flags,
+            gray_spans: gray_spans::<F>,
+            black_spans: unused_spans,
+            bit_test: unused_bit_test,
+            bit_set: unused_bit_set,
+            user: &mut context as *mut SpanContext<F> as *mut c_void,
+            clip_box: clip_box.unwrap_or(BBox {
+                xMin: 0,
+                yMin: 0,
+                xMax: 0,
+                yMax: 0,
+            }),
+        };
+        let err = unsafe {
+            ffi::FT_Outline_Render(library.raw(), self.raw as *const _ as *mut _, &mut params)
+        };
+        if let Some(payload) = context.panic {
+            panic::resume_unwind(payload);
+        }
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Render the outline into `target`, which must be a `PixelMode::Gray` (anti-aliased) or
+    /// `PixelMode::Mono` bitmap. The outline is drawn with its origin at the bottom left corner
+    /// of the bitmap, so translate it first to place it elsewhere. Pixels it does not cover are
+    /// left untouched, so the target normally has to be cleared beforehand.
+    pub fn rasterize_into(&self, library: &Library, target: &mut BitmapMut) -> FtResult<()> {
+        match target.pixel_mode()? {
+            PixelMode::Gray | PixelMode::Mono => {}
+            _ => return Err(Error::UnexpectedPixelMode),
+        }
+        let err = unsafe {
+            ffi::FT_Outline_Get_Bitmap(library.raw(), self.raw as *const _ as *mut _, target.raw())
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Copy this outline into a new `OutlineBuf`.
+    pub fn to_outline_buf(&self, library: &Library) -> FtResult<OutlineBuf> {
+        unsafe { OutlineBuf::copy_raw(library.raw(), self.raw) }
+    }
+}
+
+/// An owned outline that can be modified in place, released with `FT_Outline_Done` when
+/// dropped.
+pub struct OutlineBuf {
+    library_raw: ffi::FT_Library,
+    raw: ffi::FT_Outline,
+}
+
+impl OutlineBuf {
+    /// Allocate an outline with room for `n_points` points and `n_contours` contours. All
+    /// points, tags and contour end indices are zero and must be filled in before use.
+    pub fn new(library: &Library, n_points: usize, n_contours: usize) -> FtResult<Self> {
+        unsafe { Self::alloc(library.raw(), n_points, n_contours) }
+    }
+
+    /// Build an outline from its points, their tags and the index of the last point of each
+    /// contour. The result is validated with `check`.
+    pub fn from_parts(
+        library: &Library,
+        points: &[Vector],
+        tags: &[c_char],
+        contours: &[c_short],
+    ) -> FtResult<Self> {
+        if points.len() != tags.len() {
+            return Err(Error::InvalidArgument);
+        }
+        let mut outline = Self::new(library, points.len(), contours.len())?;
+        outline.points_mut().copy_from_slice(points);
+        outline.tags_mut().copy_from_slice(tags);
+        outline.contours_mut().copy_from_slice(contours);
+        outline.check()?;
+        Ok(outline)
+    }
+
+    /// Take ownership of an outline allocated with `FT_Outline_New` in `library_raw`.
+    pub unsafe fn from_raw(library_raw: ffi::FT_Library, raw: ffi::FT_Outline) -> Self {
+        ffi::FT_Reference_Library(library_raw);
+        OutlineBuf { library_raw, raw }
+    }
+
+    unsafe fn alloc(
+        library_raw: ffi::FT_Library,
+        n_points: usize,
+        n_contours: usize,
+    ) -> FtResult<Self> {
+        if n_points > c_short::MAX as usize || n_contours > c_short::MAX as usize {
+            return Err(Error::ArrayTooLarge);
+        }
+        let mut raw = mem::zeroed();
+        let err = ffi::FT_Outline_New(
+            library_raw,
+            n_points as ffi::FT_UInt,
+            n_contours as ffi::FT_Int,
+            &mut raw,
+        );
+        if err == ffi::FT_Err_Ok {
+            Ok(Self::from_raw(library_raw, raw))
+        } else {
+            Err(err.into())
+        }
+    }
+
+    pub(crate) unsafe fn copy_raw(
+        library_raw: ffi::FT_Library,
+        source: &ffi::FT_Outline,
+    ) -> FtResult<Self> {
+        let mut outline = Self::alloc(
+            library_raw,
+            source.n_points as usize,
+            source.n_contours as usize,
+        )?;
+        let err = ffi::FT_Outline_Copy(source, &mut outline.raw);
+        if err == ffi::FT_Err_Ok {
+            Ok(outline)
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// A read-only view of the outline.
+    pub fn outline(&self) -> Outline<'_> {
+        unsafe { Outline::from_raw(&self.raw) }
+    }
+
+    pub fn points_mut(&mut self) -> &mut [Vector] {
+        if self.raw.n_points == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.raw.points, self.raw.n_points as usize) }
+    }
+
+    pub fn tags_mut(&mut self) -> &mut [c_char] {
+        if self.raw.n_points == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.raw.tags, self.raw.n_points as usize) }
+    }
+
+    pub fn contours_mut(&mut self) -> &mut [c_short] {
+        if self.raw.n_contours == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.raw.contours, self.raw.n_contours as usize) }
+    }
+
+    /// Apply a 2x2 matrix to all points of the outline.
+    pub fn transform(&mut self, matrix: Matrix) {
+        unsafe { ffi::FT_Outline_Transform(&self.raw, &matrix) }
+    }
+
+    /// Move all points of the outline by `dx` and `dy`, in 26.6 units.
+    pub fn translate(&mut self, dx: ffi::FT_Pos, dy: ffi::FT_Pos) {
+        unsafe { ffi::FT_Outline_Translate(&self.raw, dx, dy) }
+    }
+
+    /// Make the outline bolder by `strength`, in 26.6 units. The new outline is at most
+    /// `strength` wider and higher.
+    pub fn embolden(&mut self, strength: ffi::FT_Pos) -> FtResult<()> {
+        let err = unsafe { ffi::FT_Outline_Embolden(&mut self.raw, strength) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Like `embolden`, but with different strengths in the horizontal and vertical direction.
+    pub fn embolden_xy(
+        &mut self,
+        x_strength: ffi::FT_Pos,
+        y_strength: ffi::FT_Pos,
+    ) -> FtResult<()> {
+        let err = unsafe { ffi::FT_Outline_EmboldenXY(&mut self.raw, x_strength, y_strength) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    /// Reverse the drawing direction of all contours.
+    pub fn reverse(&mut self) {
+        unsafe { ffi::FT_Outline_Reverse(&mut self.raw) }
+    }
+
+    /// Check that the contour end indices are consistent with the number of points.
+    pub fn check(&mut self) -> FtResult<()> {
+        let err = unsafe { ffi::FT_Outline_Check(&mut self.raw) };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    pub fn raw(&self) -> &ffi::FT_Outline {
+        &self.raw
+    }
+
+    pub fn raw_mut(&mut self) -> &mut ffi::FT_Outline {
+        &mut self.raw
+    }
+}
+
+impl Clone for OutlineBuf {
+    fn clone(&self) -> Self {
+        match unsafe { Self::copy_raw(self.library_raw, &self.raw) } {
+            Ok(outline) => outline,
+            Err(_) => panic!("Failed to copy outline"),
+        }
+    }
+}
+
+impl Drop for OutlineBuf {
+    fn drop(&mut self) {
+        let err = unsafe { ffi::FT_Outline_Done(self.library_raw, &mut self.raw) };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop outline");
+        }
+        let err = unsafe { ffi::FT_Done_Library(self.library_raw) };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop library")
+        }
+    }
 }
 
 const TAG_ONCURVE: c_char = 0x01;
@@ -137,7 +565,10 @@ impl<'a> ContourIterator<'a> {
             outline,
             contour_start: 0,
             contour_end_idx: outline.contours,
-            last_end_idx: outline.contours.offset(outline.n_contours as isize - 1),
+            // `contours` is null for an empty outline, so `offset` cannot be used here.
+            last_end_idx: outline
+                .contours
+                .wrapping_offset(outline.n_contours as isize - 1),
         }
     }
 }
@@ -164,3 +595,302 @@ impl<'a> Iterator for ContourIterator<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{OutlineBuf, OutlineVisitor};
+    use crate::bitmap::{BitmapMut, Pixel, PixelMode};
+    use crate::face::LoadFlag;
+    use crate::ffi::FT_Pos;
+    use crate::{BBox, BBoxMode, Error, Library, Matrix, Span, Vector};
+    use std::path::PathBuf;
+
+    fn square(library: &Library) -> OutlineBuf {
+        let points = [
+            Vector { x: 0, y: 0 },
+            Vector { x: 0, y: 640 },
+            Vector { x: 640, y: 640 },
+            Vector { x: 640, y: 0 },
+        ];
+        OutlineBuf::from_parts(library, &points, &[1; 4], &[3]).unwrap()
+    }
+
+    #[test]
+    fn outline_buf() {
+        let library = Library::init().unwrap();
+
+        let mut outline = square(&library);
+        outline.translate(64, -64);
+        assert_eq!(outline.outline().points()[0], Vector { x: 64, y: -64 });
+
+        // Scale x by 2 and shear by 0.5 in 16.16.
+        outline.transform(Matrix {
+            xx: 0x20000,
+            xy: 0x8000,
+            yx: 0,
+            yy: 0x10000,
+        });
+        assert_eq!(outline.outline().points()[1], Vector { x: 416, y: 576 });
+
+        let copy = outline.clone();
+        outline.reverse();
+        let mut reversed = copy.outline().points().to_vec();
+        reversed.reverse();
+        // FreeType 2.13 keeps the first point of each contour in place, so only compare the
+        // contour up to rotation.
+        let points = outline.outline().points();
+        assert!((0..reversed.len()).any(|i| {
+            let (head, tail) = reversed.split_at(i);
+            points[..tail.len()] == *tail && points[tail.len()..] == *head
+        }));
+
+        let mut bold = square(&library);
+        bold.embolden_xy(64, 32).unwrap();
+        assert_ne!(bold.outline().points(), square(&library).outline().points());
+
+        let empty = OutlineBuf::new(&library, 0, 0).unwrap();
+        assert!(empty.outline().points().is_empty());
+        assert!(empty.outline().tags().is_empty());
+        assert!(empty.outline().contours().is_empty());
+        assert_eq!(empty.outline().contours_iter().count(), 0);
+
+        let mut broken = OutlineBuf::new(&library, 4, 1).unwrap();
+        broken.contours_mut()[0] = 7;
+        // FreeType 2.13 reports `InvalidOutline`, older versions `InvalidArgument`.
+        assert!(matches!(
+            broken.check(),
+            Err(Error::InvalidOutline | Error::InvalidArgument)
+        ));
+        assert_eq!(
+            OutlineBuf::from_parts(&library, &[Vector { x: 0, y: 0 }], &[], &[0]).err(),
+            Some(Error::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let library = Library::init().unwrap();
+
+        // A single conic arc peaking at half the height of its control point.
+        let points = [
+            Vector { x: 10, y: 0 },
+            Vector { x: 330, y: 640 },
+            Vector { x: 650, y: 0 },
+        ];
+        let outline = OutlineBuf::from_parts(&library, &points, &[1, 0, 1], &[2]).unwrap();
+        let outline = outline.outline();
+
+        let cbox = outline.cbox(BBoxMode::Subpixels);
+        assert_eq!(
+            (cbox.xMin, cbox.yMin, cbox.xMax, cbox.yMax),
+            (10, 0, 650, 640)
+        );
+        let bbox = outline.exact_bbox(BBoxMode::Unscaled).unwrap();
+        assert_eq!(
+            (bbox.xMin, bbox.yMin, bbox.xMax, bbox.yMax),
+            (10, 0, 650, 320)
+        );
+
+        let bbox = outline.exact_bbox(BBoxMode::Gridfit).unwrap();
+        assert_eq!(
+            (bbox.xMin, bbox.yMin, bbox.xMax, bbox.yMax),
+            (0, 0, 704, 320)
+        );
+        let bbox = outline.exact_bbox(BBoxMode::Truncate).unwrap();
+        assert_eq!((bbox.xMin, bbox.yMin, bbox.xMax, bbox.yMax), (0, 0, 10, 5));
+        let bbox = outline.exact_bbox(BBoxMode::Pixels).unwrap();
+        assert_eq!((bbox.xMin, bbox.yMin, bbox.xMax, bbox.yMax), (0, 0, 11, 5));
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Segment {
+        Move(FT_Pos, FT_Pos),
+        Line(FT_Pos, FT_Pos),
+        Conic(FT_Pos, FT_Pos, FT_Pos, FT_Pos),
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<Segment>);
+
+    impl OutlineVisitor for Recorder {
+        fn move_to(&mut self, to: Vector) {
+            self.0.push(Segment::Move(to.x, to.y));
+        }
+        fn line_to(&mut self, to: Vector) {
+            self.0.push(Segment::Line(to.x, to.y));
+        }
+        fn conic_to(&mut self, control: Vector, to: Vector) {
+            self.0
+                .push(Segment::Conic(control.x, control.y, to.x, to.y));
+        }
+        fn cubic_to(&mut self, _: Vector, _: Vector, _: Vector) {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn decompose() {
+        use Segment::*;
+
+        let library = Library::init().unwrap();
+        let square = square(&library);
+        let mut recorder = Recorder::default();
+        square.outline().decompose(&mut recorder, 0, 0).unwrap();
+        assert_eq!(
+            recorder.0,
+            [
+                Move(0, 0),
+                Line(0, 640),
+                Line(640, 640),
+                Line(640, 0),
+                Line(0, 0)
+            ]
+        );
+
+        // Two consecutive off points imply an on point between them.
+        let points = [
+            Vector { x: 0, y: 0 },
+            Vector { x: 0, y: 64 },
+            Vector { x: 64, y: 64 },
+            Vector { x: 64, y: 0 },
+        ];
+        let curve = OutlineBuf::from_parts(&library, &points, &[1, 0, 0, 1], &[3]).unwrap();
+        let mut recorder = Recorder::default();
+        curve.outline().decompose(&mut recorder, 1, 10).unwrap();
+        assert_eq!(
+            recorder.0,
+            [
+                Move(-10, -10),
+                Conic(-10, 118, 54, 118),
+                Conic(118, 118, 118, -10),
+                Line(-10, -10)
+            ]
+        );
+    }
+
+    #[test]
+    fn render_spans() {
+        let library = Library::init().unwrap();
+        let mut square = square(&library);
+        // Half a pixel to the right, so the edge columns are half covered.
+        square.translate(32, 0);
+
+        let mut rows = Vec::new();
+        square
+            .outline()
+            .render_spans(&library, None, &mut |y, spans: &[Span]| {
+                let spans: Vec<_> = spans.iter().map(|s| (s.x, s.len, s.coverage)).collect();
+                rows.push((y, spans));
+            })
+            .unwrap();
+        assert_eq!(rows.len(), 10);
+        for (i, (y, spans)) in rows.iter().enumerate() {
+            assert_eq!(*y, i as i32);
+            assert_eq!(spans, &[(0, 1, 128), (1, 9, 255), (10, 1, 128)]);
+        }
+
+        let clip = BBox {
+            xMin: 2,
+            yMin: 3,
+            xMax: 4,
+            yMax: 5,
+        };
+        let mut rows = Vec::new();
+        square
+            .outline()
+            .render_spans(&library, Some(clip), &mut |y, spans: &[Span]| {
+                let spans: Vec<_> = spans.iter().map(|s| (s.x, s.len, s.coverage)).collect();
+                rows.push((y, spans));
+            })
+            .unwrap();
+        assert_eq!(rows, [(3, vec![(2, 2, 255)]), (4, vec![(2, 2, 255)])]);
+    }
+
+    #[test]
+    fn rasterize_into() {
+        let library = Library::init().unwrap();
+        let mut square = square(&library);
+        square.translate(64, 64);
+
+        // A 12x12 region with a pitch of 16 inside a larger buffer, filled with a marker.
+        let mut buffer = vec![7u8; 16 * 12];
+        let mut target = BitmapMut::new(&mut buffer, 12, 12, 16, PixelMode::Gray).unwrap();
+        square
+            .outline()
+            .rasterize_into(&library, &mut target)
+            .unwrap();
+        for (y, row) in buffer.chunks(16).enumerate() {
+            let inside = (1..11).contains(&y);
+            for (x, &value) in row.iter().enumerate() {
+                let expected = if inside && (1..11).contains(&x) {
+                    255
+                } else {
+                    7
+                };
+                assert_eq!(value, expected, "pixel {}, {}", x, y);
+            }
+        }
+
+        let mut mono = vec![0u8; 2 * 12];
+        let mut target = BitmapMut::new(&mut mono, 12, 12, -2, PixelMode::Mono).unwrap();
+        square
+            .outline()
+            .rasterize_into(&library, &mut target)
+            .unwrap();
+        let bitmap = target.as_bitmap();
+        assert_eq!(bitmap.pixel(0, 0), Pixel::Coverage(0));
+        assert_eq!(bitmap.pixel(1, 1), Pixel::Coverage(255));
+        assert_eq!(bitmap.pixel(11, 10), Pixel::Coverage(0));
+        assert_eq!(&mono[..4], &[0, 0, 0b0111_1111, 0b1110_0000]);
+
+        let mut small = vec![0u8; 10];
+        assert!(BitmapMut::new(&mut small, 4, 4, 4, PixelMode::Gray).is_err());
+        assert!(BitmapMut::new(&mut small, 4, 2, 3, PixelMode::Gray).is_err());
+        let mut target = BitmapMut::new(&mut small, 1, 2, 4, PixelMode::Bgra).unwrap();
+        assert_eq!(
+            square.outline().rasterize_into(&library, &mut target),
+            Err(Error::UnexpectedPixelMode)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "visitor panic")]
+    fn decompose_propagates_panics() {
+        struct Panicker;
+        impl OutlineVisitor for Panicker {
+            fn move_to(&mut self, _: Vector) {
+                panic!("visitor panic");
+            }
+            fn line_to(&mut self, _: Vector) {}
+            fn conic_to(&mut self, _: Vector, _: Vector) {}
+            fn cubic_to(&mut self, _: Vector, _: Vector, _: Vector) {}
+        }
+
+        let library = Library::init().unwrap();
+        let _ = square(&library).outline().decompose(&mut Panicker, 0, 0);
+    }
+
+    #[test]
+    fn outline_buf_from_glyph_slot() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+        face.set_pixel_sizes(0, 32).unwrap();
+        face.load_char('A' as usize, LoadFlag::DEFAULT).unwrap();
+
+        let mut outline = face.glyph().outline_buf().unwrap();
+        outline.embolden(64).unwrap();
+        outline.check().unwrap();
+        let original = face.glyph().outline().unwrap();
+        assert_eq!(outline.outline().contours(), original.contours());
+        assert_ne!(outline.outline().points(), original.points());
+
+        face.load_char('A' as usize, LoadFlag::RENDER).unwrap();
+        assert_eq!(
+            face.glyph().outline_buf().err(),
+            Some(Error::InvalidGlyphFormat)
+        );
+    }
+}
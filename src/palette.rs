@@ -0,0 +1,121 @@
+use crate::ffi_ext;
+use std::slice;
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PaletteFlag: u16 {
+        const FOR_LIGHT_BACKGROUND = ffi_ext::FT_PALETTE_FOR_LIGHT_BACKGROUND;
+        const FOR_DARK_BACKGROUND  = ffi_ext::FT_PALETTE_FOR_DARK_BACKGROUND;
+    }
+}
+
+/// A palette entry. The color channels are not pre-multiplied and in the sRGB colorspace.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+impl Color {
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Color {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+}
+
+impl From<ffi_ext::FT_Color> for Color {
+    fn from(color: ffi_ext::FT_Color) -> Self {
+        Color::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+impl From<Color> for ffi_ext::FT_Color {
+    fn from(color: Color) -> Self {
+        ffi_ext::FT_Color {
+            blue: color.blue,
+            green: color.green,
+            red: color.red,
+            alpha: color.alpha,
+        }
+    }
+}
+
+/// A description of a single CPAL palette.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    /// The `name` table entry for the palette name, if any.
+    pub name_id: Option<u16>,
+    /// Whether the palette is suited for light or dark backgrounds.
+    pub flags: PaletteFlag,
+}
+
+/// The palettes of a color font, as returned by `Face::palette_data`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteData {
+    /// The palettes of the font. Pass an index into this list to `Face::select_palette`.
+    pub palettes: Vec<Palette>,
+    /// The `name` table entries for the palette entries, if any. All palettes have exactly this
+    /// many entries.
+    pub entry_name_ids: Vec<Option<u16>>,
+}
+
+impl PaletteData {
+    pub(crate) unsafe fn from_raw(raw: &ffi_ext::FT_Palette_Data) -> Self {
+        let palettes = (0..raw.num_palettes as usize)
+            .map(|i| Palette {
+                name_id: name_id_at(raw.palette_name_ids, i),
+                flags: if raw.palette_flags.is_null() {
+                    PaletteFlag::empty()
+                } else {
+                    PaletteFlag::from_bits_truncate(*raw.palette_flags.add(i))
+                },
+            })
+            .collect();
+        let entry_name_ids = (0..raw.num_palette_entries as usize)
+            .map(|i| name_id_at(raw.palette_entry_name_ids, i))
+            .collect();
+        PaletteData {
+            palettes,
+            entry_name_ids,
+        }
+    }
+
+    /// The number of palettes in the font.
+    pub fn num_palettes(&self) -> usize {
+        self.palettes.len()
+    }
+
+    /// The number of entries in each palette.
+    pub fn num_palette_entries(&self) -> usize {
+        self.entry_name_ids.len()
+    }
+}
+
+// Name ID arrays are optional and use 0xFFFF for entries without a name.
+unsafe fn name_id_at(ids: *const u16, i: usize) -> Option<u16> {
+    if ids.is_null() {
+        None
+    } else {
+        match *ids.add(i) {
+            0xFFFF => None,
+            id => Some(id),
+        }
+    }
+}
+
+pub(crate) unsafe fn entries_from_raw(raw: *const ffi_ext::FT_Color, len: usize) -> Vec<Color> {
+    if raw.is_null() || len == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(raw, len)
+            .iter()
+            .map(|&color| color.into())
+            .collect()
+    }
+}
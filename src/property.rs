@@ -0,0 +1,310 @@
+use crate::{ffi, Error, Face, FtResult};
+use libc::c_void;
+use std::marker::PhantomData;
+
+/// A hinting module whose properties can be changed with `Library::set_property`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Module {
+    Autofitter,
+    Cff,
+    Type1,
+    T1Cid,
+}
+
+impl Module {
+    fn name(self) -> &'static [u8] {
+        match self {
+            Module::Autofitter => b"autofitter\0",
+            Module::Cff => b"cff\0",
+            Module::Type1 => b"type1\0",
+            Module::T1Cid => b"t1cid\0",
+        }
+    }
+}
+
+/// The bytecode interpreter used by the TrueType driver.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InterpreterVersion {
+    /// The classic interpreter, as used before FreeType 2.7.
+    V35 = 35,
+    /// The ClearType-like interpreter of the "Infinality" patches.
+    V38 = 38,
+    /// The minimal ClearType-like interpreter, the default since FreeType 2.7.
+    V40 = 40,
+}
+
+/// The hinting engine used by the CFF, Type 1 and CID drivers.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HintingEngine {
+    FreeType = 0,
+    Adobe = 1,
+}
+
+/// A script known to the auto-hinter, for the `fallback-script` and `default-script` properties.
+///
+/// The values follow FreeType's internal script list, which is the same from 2.12.1, the oldest
+/// version `freetype-sys` accepts, through 2.13. Builds without the auto-hinter's Indic support
+/// leave out the scripts from `Limbu` to `Tibetan`, which shifts `Han`.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    Adlam = 0,
+    Arabic = 1,
+    Armenian = 2,
+    Avestan = 3,
+    Bamum = 4,
+    Bengali = 5,
+    Buhid = 6,
+    Chakma = 7,
+    CanadianSyllabics = 8,
+    Carian = 9,
+    Cherokee = 10,
+    Coptic = 11,
+    Cypriot = 12,
+    Cyrillic = 13,
+    Devanagari = 14,
+    Deseret = 15,
+    Ethiopic = 16,
+    /// Georgian in Mkhedruli.
+    Georgian = 17,
+    /// Georgian in Khutsuri.
+    GeorgianKhutsuri = 18,
+    Glagolitic = 19,
+    Gothic = 20,
+    Greek = 21,
+    Gujarati = 22,
+    Gurmukhi = 23,
+    Hebrew = 24,
+    KayahLi = 25,
+    Khmer = 26,
+    KhmerSymbols = 27,
+    Kannada = 28,
+    Lao = 29,
+    Latin = 30,
+    /// Latin subscript glyphs without a Unicode character of their own.
+    LatinSubscript = 31,
+    /// Latin superscript glyphs without a Unicode character of their own.
+    LatinSuperscript = 32,
+    Lisu = 33,
+    Malayalam = 34,
+    Medefaidrin = 35,
+    Mongolian = 36,
+    Myanmar = 37,
+    Nko = 38,
+    /// No script; glyphs assigned to it are not hinted.
+    None = 39,
+    OlChiki = 40,
+    OldTurkic = 41,
+    Osage = 42,
+    Osmanya = 43,
+    HanifiRohingya = 44,
+    Saurashtra = 45,
+    Shavian = 46,
+    Sinhala = 47,
+    Sundanese = 48,
+    Tamil = 49,
+    TaiViet = 50,
+    Telugu = 51,
+    Tifinagh = 52,
+    Thai = 53,
+    Vai = 54,
+    Limbu = 55,
+    Oriya = 56,
+    SylotiNagri = 57,
+    Tibetan = 58,
+    /// CJKV ideographs.
+    Han = 59,
+}
+
+impl Script {
+    fn from_raw(value: u32) -> Option<Self> {
+        use self::Script::*;
+        #[rustfmt::skip]
+        const SCRIPTS: [Script; 60] = [
+            Adlam, Arabic, Armenian, Avestan, Bamum, Bengali, Buhid, Chakma, CanadianSyllabics,
+            Carian, Cherokee, Coptic, Cypriot, Cyrillic, Devanagari, Deseret, Ethiopic, Georgian,
+            GeorgianKhutsuri, Glagolitic, Gothic, Greek, Gujarati, Gurmukhi, Hebrew, KayahLi, Khmer,
+            KhmerSymbols, Kannada, Lao, Latin, LatinSubscript, LatinSuperscript, Lisu, Malayalam,
+            Medefaidrin, Mongolian, Myanmar, Nko, None, OlChiki, OldTurkic, Osage, Osmanya,
+            HanifiRohingya, Saurashtra, Shavian, Sinhala, Sundanese, Tamil, TaiViet, Telugu,
+            Tifinagh, Thai, Vai, Limbu, Oriya, SylotiNagri, Tibetan, Han,
+        ];
+        SCRIPTS.get(value as usize).copied()
+    }
+}
+
+/// The `increase-x-height` setting of the auto-hinter, which applies to a single face.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IncreaseXHeight<'a> {
+    face_raw: ffi::FT_Face,
+    /// x-height values are rounded up for ppem values from 6 up to this limit; 0 disables it.
+    pub limit: u32,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> IncreaseXHeight<'a> {
+    pub fn new<BYTES>(face: &'a Face<BYTES>, limit: u32) -> Self {
+        IncreaseXHeight {
+            face_raw: face.raw() as *const _ as ffi::FT_Face,
+            limit,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[repr(C)]
+struct FT_Prop_IncreaseXHeight {
+    face: ffi::FT_Face,
+    limit: ffi::FT_UInt,
+}
+
+/// A typed FreeType module property, for use with `Library::set_property` and
+/// `Library::get_property`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Property<'a> {
+    /// The TrueType `interpreter-version`.
+    InterpreterVersion(InterpreterVersion),
+    /// The `hinting-engine` of the CFF, Type 1 or CID driver.
+    HintingEngine(Module, HintingEngine),
+    /// Whether stem darkening is disabled (`no-stem-darkening`).
+    NoStemDarkening(Module, bool),
+    /// The stem darkening curve, as four (stem width, darkening amount) pairs.
+    DarkeningParameters(Module, [i32; 8]),
+    /// The auto-hinter `fallback-script`, used for glyphs that are not covered by any script.
+    FallbackScript(Script),
+    /// The auto-hinter `default-script`, used for glyphs that are not covered by OpenType
+    /// features.
+    DefaultScript(Script),
+    /// The auto-hinter `increase-x-height` setting of a face.
+    IncreaseXHeight(IncreaseXHeight<'a>),
+    /// The auto-hinter `warping` switch. FreeType 2.11 removed warping, so newer versions
+    /// reject this property.
+    Warping(bool),
+}
+
+impl Property<'_> {
+    fn names(&self) -> (&'static [u8], &'static [u8]) {
+        match *self {
+            Property::InterpreterVersion(_) => (b"truetype\0", b"interpreter-version\0"),
+            Property::HintingEngine(module, _) => (module.name(), b"hinting-engine\0"),
+            Property::NoStemDarkening(module, _) => (module.name(), b"no-stem-darkening\0"),
+            Property::DarkeningParameters(module, _) => (module.name(), b"darkening-parameters\0"),
+            Property::FallbackScript(_) => (b"autofitter\0", b"fallback-script\0"),
+            Property::DefaultScript(_) => (b"autofitter\0", b"default-script\0"),
+            Property::IncreaseXHeight(_) => (b"autofitter\0", b"increase-x-height\0"),
+            Property::Warping(_) => (b"autofitter\0", b"warping\0"),
+        }
+    }
+
+    pub(crate) fn set(&self, library_raw: ffi::FT_Library) -> FtResult<()> {
+        let (module, name) = self.names();
+        let set = |value: *const c_void| unsafe {
+            ffi::FT_Property_Set(
+                library_raw,
+                module.as_ptr() as *const _,
+                name.as_ptr() as *const _,
+                value,
+            )
+        };
+        let err = match *self {
+            Property::InterpreterVersion(version) => {
+                set(&(version as ffi::FT_UInt) as *const _ as _)
+            }
+            Property::HintingEngine(_, engine) => set(&(engine as ffi::FT_UInt) as *const _ as _),
+            Property::NoStemDarkening(_, value) | Property::Warping(value) => {
+                set(&(value as ffi::FT_Bool) as *const _ as _)
+            }
+            Property::DarkeningParameters(_, ref params) => set(params.as_ptr() as _),
+            Property::FallbackScript(script) | Property::DefaultScript(script) => {
+                set(&(script as ffi::FT_UInt) as *const _ as _)
+            }
+            Property::IncreaseXHeight(prop) => set(&FT_Prop_IncreaseXHeight {
+                face: prop.face_raw,
+                limit: prop.limit,
+            } as *const _ as _),
+        };
+        if err == ffi::FT_Err_Ok {
+            Ok(())
+        } else {
+            Err(err.into())
+        }
+    }
+
+    pub(crate) fn get(&mut self, library_raw: ffi::FT_Library) -> FtResult<()> {
+        let (module, name) = self.names();
+        let get = |value: *mut c_void| {
+            let err = unsafe {
+                ffi::FT_Property_Get(
+                    library_raw,
+                    module.as_ptr() as *const _,
+                    name.as_ptr() as *const _,
+                    value,
+                )
+            };
+            if err == ffi::FT_Err_Ok {
+                Ok(())
+            } else {
+                Err(Error::from(err))
+            }
+        };
+        match self {
+            Property::InterpreterVersion(version) => {
+                let mut value: ffi::FT_UInt = 0;
+                get(&mut value as *mut _ as _)?;
+                *version = match value {
+                    35 => InterpreterVersion::V35,
+                    38 => InterpreterVersion::V38,
+                    40 => InterpreterVersion::V40,
+                    _ => return Err(Error::InvalidArgument),
+                };
+            }
+            Property::HintingEngine(_, engine) => {
+                let mut value: ffi::FT_UInt = 0;
+                get(&mut value as *mut _ as _)?;
+                *engine = match value {
+                    0 => HintingEngine::FreeType,
+                    1 => HintingEngine::Adobe,
+                    _ => return Err(Error::InvalidArgument),
+                };
+            }
+            Property::NoStemDarkening(_, flag) | Property::Warping(flag) => {
+                let mut value: ffi::FT_Bool = 0;
+                get(&mut value as *mut _ as _)?;
+                *flag = value != 0;
+            }
+            Property::DarkeningParameters(_, params) => get(params.as_mut_ptr() as _)?,
+            Property::FallbackScript(script) | Property::DefaultScript(script) => {
+                let mut value: ffi::FT_UInt = 0;
+                get(&mut value as *mut _ as _)?;
+                *script = Script::from_raw(value).ok_or(Error::InvalidArgument)?;
+            }
+            Property::IncreaseXHeight(prop) => {
+                let mut value = FT_Prop_IncreaseXHeight {
+                    face: prop.face_raw,
+                    limit: 0,
+                };
+                get(&mut value as *mut _ as _)?;
+                prop.limit = value.limit;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Script;
+
+    #[test]
+    fn script_values() {
+        for value in 0..=Script::Han as u32 {
+            assert_eq!(
+                Script::from_raw(value).map(|script| script as u32),
+                Some(value)
+            );
+        }
+        assert_eq!(Script::from_raw(Script::Han as u32 + 1), None);
+    }
+}
@@ -0,0 +1,620 @@
+use crate::ffi;
+use std::marker::PhantomData;
+use std::ptr::null_mut;
+use std::slice;
+
+/// Well-known entries of the SFNT `name` table. Font-specific entries (such as the names of
+/// variation axes and palettes) use IDs of 256 and above.
+#[repr(u16)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NameId {
+    Copyright = 0,
+    FontFamily = 1,
+    FontSubfamily = 2,
+    UniqueId = 3,
+    FullName = 4,
+    Version = 5,
+    PostscriptName = 6,
+    Trademark = 7,
+    Manufacturer = 8,
+    Designer = 9,
+    Description = 10,
+    VendorUrl = 11,
+    DesignerUrl = 12,
+    License = 13,
+    LicenseUrl = 14,
+    TypographicFamily = 16,
+    TypographicSubfamily = 17,
+    MacFullName = 18,
+    SampleText = 19,
+    CidFindfontName = 20,
+    WwsFamily = 21,
+    WwsSubfamily = 22,
+    LightBackground = 23,
+    DarkBackground = 24,
+    VariationsPostscriptNamePrefix = 25,
+}
+
+impl NameId {
+    /// The entry to use in place of this one if the font lacks it.
+    pub(crate) fn fallback(self) -> Option<NameId> {
+        match self {
+            NameId::TypographicFamily => Some(NameId::FontFamily),
+            NameId::TypographicSubfamily => Some(NameId::FontSubfamily),
+            NameId::WwsFamily => Some(NameId::TypographicFamily),
+            NameId::WwsSubfamily => Some(NameId::TypographicSubfamily),
+            _ => None,
+        }
+    }
+}
+
+/// A single record of the SFNT `name` table, as returned by `Face::sfnt_names`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SfntName {
+    pub platform_id: u16,
+    pub encoding_id: u16,
+    pub language_id: u16,
+    pub name_id: u16,
+    /// The BCP-47 language tag of the record, if the language ID is known.
+    pub language_tag: Option<String>,
+    /// The undecoded name string.
+    pub bytes: Vec<u8>,
+}
+
+impl SfntName {
+    /// Decode the name string. UTF-16BE (Unicode and Windows platforms) and Mac Roman strings
+    /// are supported; `None` is returned for other encodings.
+    pub fn decode(&self) -> Option<String> {
+        match (self.platform_id, self.encoding_id) {
+            (ffi::TT_PLATFORM_APPLE_UNICODE, _)
+            | (
+                ffi::TT_PLATFORM_MICROSOFT,
+                ffi::TT_MS_ID_SYMBOL_CS | ffi::TT_MS_ID_UNICODE_CS | ffi::TT_MS_ID_UCS_4,
+            ) => decode_utf16_be(&self.bytes),
+            (ffi::TT_PLATFORM_MACINTOSH, ffi::TT_MAC_ID_ROMAN) => {
+                Some(decode_mac_roman(&self.bytes))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An iterator over the records of the SFNT `name` table, returned by `Face::sfnt_names`.
+pub struct SfntNames<'a> {
+    face_raw: ffi::FT_Face,
+    index: u32,
+    count: u32,
+    marker: PhantomData<&'a ()>,
+}
+
+impl SfntNames<'_> {
+    pub(crate) unsafe fn from_raw(face_raw: ffi::FT_Face) -> Self {
+        SfntNames {
+            face_raw,
+            index: 0,
+            count: ffi::FT_Get_Sfnt_Name_Count(face_raw),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl Iterator for SfntNames<'_> {
+    type Item = SfntName;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.count {
+            let mut raw = ffi::FT_SfntName {
+                platform_id: 0,
+                encoding_id: 0,
+                language_id: 0,
+                name_id: 0,
+                string: null_mut(),
+                string_len: 0,
+            };
+            let err = unsafe { ffi::FT_Get_Sfnt_Name(self.face_raw, self.index, &mut raw) };
+            self.index += 1;
+            if err != ffi::FT_Err_Ok {
+                continue;
+            }
+            return Some(SfntName {
+                platform_id: raw.platform_id,
+                encoding_id: raw.encoding_id,
+                language_id: raw.language_id,
+                name_id: raw.name_id,
+                language_tag: unsafe {
+                    language_tag(self.face_raw, raw.platform_id, raw.language_id)
+                },
+                bytes: unsafe { bytes_from_raw(raw.string, raw.string_len) },
+            });
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.count - self.index) as usize))
+    }
+}
+
+/// Pick the best record for `name_id` from `names`, preferring an exact match of `language`,
+/// then a match of its primary language subtag, then English, then any decodable record.
+pub(crate) fn best_localized_name<I>(names: I, name_id: u16, language: &str) -> Option<String>
+where
+    I: Iterator<Item = SfntName>,
+{
+    let primary = |tag: &str| tag.split('-').next().unwrap_or("").to_ascii_lowercase();
+    let wanted_primary = primary(language);
+
+    names
+        .filter(|name| name.name_id == name_id)
+        .filter_map(|name| {
+            let string = name.decode()?;
+            let rank = match name.language_tag.as_deref() {
+                Some(tag) if tag.eq_ignore_ascii_case(language) => 0,
+                Some(tag) if primary(tag) == wanted_primary => 1,
+                Some("en-US") => 2,
+                Some(tag) if primary(tag) == "en" => 3,
+                _ => 4,
+            };
+            // Windows records are preferred over Mac ones of the same rank.
+            let platform_rank = (name.platform_id != ffi::TT_PLATFORM_MICROSOFT) as u8;
+            Some(((rank, platform_rank), string))
+        })
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, string)| string)
+}
+
+unsafe fn bytes_from_raw(string: *const u8, len: u32) -> Vec<u8> {
+    if string.is_null() || len == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(string, len as usize).to_vec()
+    }
+}
+
+unsafe fn language_tag(
+    face_raw: ffi::FT_Face,
+    platform_id: u16,
+    language_id: u16,
+) -> Option<String> {
+    if language_id >= 0x8000 {
+        // Language tag records of `name` table format 1, stored as UTF-16BE.
+        let mut raw = ffi::FT_SfntName {
+            platform_id: 0,
+            encoding_id: 0,
+            language_id: 0,
+            name_id: 0,
+            string: null_mut(),
+            string_len: 0,
+        };
+        let err = ffi::FT_Get_Sfnt_LangTag(face_raw, language_id as u32, &mut raw);
+        if err != ffi::FT_Err_Ok {
+            return None;
+        }
+        return decode_utf16_be(&bytes_from_raw(raw.string, raw.string_len));
+    }
+
+    let table = match platform_id {
+        ffi::TT_PLATFORM_MICROSOFT => MS_LANGUAGE_TAGS,
+        ffi::TT_PLATFORM_MACINTOSH => MAC_LANGUAGE_TAGS,
+        _ => return None,
+    };
+    table
+        .binary_search_by_key(&language_id, |&(id, _)| id)
+        .ok()
+        .map(|i| table[i].1.to_owned())
+}
+
+fn decode_utf16_be(bytes: &[u8]) -> Option<String> {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+    char::decode_utf16(units).collect::<Result<_, _>>().ok()
+}
+
+fn decode_mac_roman(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| {
+            if b < 0x80 {
+                b as char
+            } else {
+                MAC_ROMAN_HIGH[(b - 0x80) as usize]
+            }
+        })
+        .collect()
+}
+
+#[rustfmt::skip]
+const MAC_ROMAN_HIGH: [char; 128] = [
+    'Ä', 'Å', 'Ç', 'É', 'Ñ', 'Ö', 'Ü', 'á', 'à', 'â', 'ä', 'ã', 'å', 'ç', 'é', 'è',
+    'ê', 'ë', 'í', 'ì', 'î', 'ï', 'ñ', 'ó', 'ò', 'ô', 'ö', 'õ', 'ú', 'ù', 'û', 'ü',
+    '†', '°', '¢', '£', '§', '•', '¶', 'ß', '®', '©', '™', '´', '¨', '≠', 'Æ', 'Ø',
+    '∞', '±', '≤', '≥', '¥', 'µ', '∂', '∑', '∏', 'π', '∫', 'ª', 'º', 'Ω', 'æ', 'ø',
+    '¿', '¡', '¬', '√', 'ƒ', '≈', '∆', '«', '»', '…', '\u{A0}', 'À', 'Ã', 'Õ', 'Œ', 'œ',
+    '–', '—', '“', '”', '‘', '’', '÷', '◊', 'ÿ', 'Ÿ', '⁄', '€', '‹', '›', 'ﬁ', 'ﬂ',
+    '‡', '·', '‚', '„', '‰', 'Â', 'Ê', 'Á', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', 'Ó', 'Ô',
+    '\u{F8FF}', 'Ò', 'Ú', 'Û', 'Ù', 'ı', 'ˆ', '˜', '¯', '˘', '˙', '˚', '¸', '˝', '˛', 'ˇ',
+];
+
+// Sorted by language ID.
+const MAC_LANGUAGE_TAGS: &[(u16, &str)] = &[
+    (0, "en"),
+    (1, "fr"),
+    (2, "de"),
+    (3, "it"),
+    (4, "nl"),
+    (5, "sv"),
+    (6, "es"),
+    (7, "da"),
+    (8, "pt"),
+    (9, "no"),
+    (10, "he"),
+    (11, "ja"),
+    (12, "ar"),
+    (13, "fi"),
+    (14, "el"),
+    (15, "is"),
+    (16, "mt"),
+    (17, "tr"),
+    (18, "hr"),
+    (19, "zh-Hant"),
+    (20, "ur"),
+    (21, "hi"),
+    (22, "th"),
+    (23, "ko"),
+    (24, "lt"),
+    (25, "pl"),
+    (26, "hu"),
+    (27, "et"),
+    (28, "lv"),
+    (29, "se"),
+    (30, "fo"),
+    (31, "fa"),
+    (32, "ru"),
+    (33, "zh-Hans"),
+    (34, "nl-BE"),
+    (35, "ga"),
+    (36, "sq"),
+    (37, "ro"),
+    (38, "cs"),
+    (39, "sk"),
+    (40, "sl"),
+    (41, "yi"),
+    (42, "sr"),
+    (43, "mk"),
+    (44, "bg"),
+    (45, "uk"),
+    (46, "be"),
+    (47, "uz"),
+    (48, "kk"),
+    (49, "az-Cyrl"),
+    (50, "az-Arab"),
+    (51, "hy"),
+    (52, "ka"),
+    (53, "mo"),
+    (54, "ky"),
+    (55, "tg"),
+    (56, "tk"),
+    (57, "mn-Mong"),
+    (58, "mn-Cyrl"),
+    (59, "ps"),
+    (60, "ku"),
+    (61, "ks"),
+    (62, "sd"),
+    (63, "bo"),
+    (64, "ne"),
+    (65, "sa"),
+    (66, "mr"),
+    (67, "bn"),
+    (68, "as"),
+    (69, "gu"),
+    (70, "pa"),
+    (71, "or"),
+    (72, "ml"),
+    (73, "kn"),
+    (74, "ta"),
+    (75, "te"),
+    (76, "si"),
+    (77, "my"),
+    (78, "km"),
+    (79, "lo"),
+    (80, "vi"),
+    (81, "id"),
+    (82, "tl"),
+    (83, "ms"),
+    (84, "ms-Arab"),
+    (85, "am"),
+    (86, "ti"),
+    (87, "om"),
+    (88, "so"),
+    (89, "sw"),
+    (90, "rw"),
+    (91, "rn"),
+    (92, "ny"),
+    (93, "mg"),
+    (94, "eo"),
+    (128, "cy"),
+    (129, "eu"),
+    (130, "ca"),
+    (131, "la"),
+    (132, "qu"),
+    (133, "gn"),
+    (134, "ay"),
+    (135, "tt"),
+    (136, "ug"),
+    (137, "dz"),
+    (138, "jv"),
+    (139, "su"),
+    (140, "gl"),
+    (141, "af"),
+    (142, "br"),
+    (143, "iu"),
+    (144, "gd"),
+    (145, "gv"),
+    (146, "ga"),
+    (147, "to"),
+    (148, "el-polyton"),
+    (149, "kl"),
+    (150, "az"),
+];
+
+// Sorted by language ID.
+const MS_LANGUAGE_TAGS: &[(u16, &str)] = &[
+    (0x0401, "ar-SA"),
+    (0x0402, "bg-BG"),
+    (0x0403, "ca-ES"),
+    (0x0404, "zh-TW"),
+    (0x0405, "cs-CZ"),
+    (0x0406, "da-DK"),
+    (0x0407, "de-DE"),
+    (0x0408, "el-GR"),
+    (0x0409, "en-US"),
+    (0x040A, "es-ES"),
+    (0x040B, "fi-FI"),
+    (0x040C, "fr-FR"),
+    (0x040D, "he-IL"),
+    (0x040E, "hu-HU"),
+    (0x040F, "is-IS"),
+    (0x0410, "it-IT"),
+    (0x0411, "ja-JP"),
+    (0x0412, "ko-KR"),
+    (0x0413, "nl-NL"),
+    (0x0414, "nb-NO"),
+    (0x0415, "pl-PL"),
+    (0x0416, "pt-BR"),
+    (0x0417, "rm-CH"),
+    (0x0418, "ro-RO"),
+    (0x0419, "ru-RU"),
+    (0x041A, "hr-HR"),
+    (0x041B, "sk-SK"),
+    (0x041C, "sq-AL"),
+    (0x041D, "sv-SE"),
+    (0x041E, "th-TH"),
+    (0x041F, "tr-TR"),
+    (0x0420, "ur-PK"),
+    (0x0421, "id-ID"),
+    (0x0422, "uk-UA"),
+    (0x0423, "be-BY"),
+    (0x0424, "sl-SI"),
+    (0x0425, "et-EE"),
+    (0x0426, "lv-LV"),
+    (0x0427, "lt-LT"),
+    (0x0428, "tg-TJ"),
+    (0x0429, "fa-IR"),
+    (0x042A, "vi-VN"),
+    (0x042B, "hy-AM"),
+    (0x042C, "az-Latn-AZ"),
+    (0x042D, "eu-ES"),
+    (0x042E, "hsb-DE"),
+    (0x042F, "mk-MK"),
+    (0x0432, "tn-ZA"),
+    (0x0434, "xh-ZA"),
+    (0x0435, "zu-ZA"),
+    (0x0436, "af-ZA"),
+    (0x0437, "ka-GE"),
+    (0x0438, "fo-FO"),
+    (0x0439, "hi-IN"),
+    (0x043A, "mt-MT"),
+    (0x043B, "se-NO"),
+    (0x043E, "ms-MY"),
+    (0x043F, "kk-KZ"),
+    (0x0440, "ky-KG"),
+    (0x0441, "sw-KE"),
+    (0x0442, "tk-TM"),
+    (0x0443, "uz-Latn-UZ"),
+    (0x0444, "tt-RU"),
+    (0x0445, "bn-IN"),
+    (0x0446, "pa-IN"),
+    (0x0447, "gu-IN"),
+    (0x0448, "or-IN"),
+    (0x0449, "ta-IN"),
+    (0x044A, "te-IN"),
+    (0x044B, "kn-IN"),
+    (0x044C, "ml-IN"),
+    (0x044D, "as-IN"),
+    (0x044E, "mr-IN"),
+    (0x044F, "sa-IN"),
+    (0x0450, "mn-MN"),
+    (0x0451, "bo-CN"),
+    (0x0452, "cy-GB"),
+    (0x0453, "km-KH"),
+    (0x0454, "lo-LA"),
+    (0x0456, "gl-ES"),
+    (0x0457, "kok-IN"),
+    (0x045A, "syr-SY"),
+    (0x045B, "si-LK"),
+    (0x045D, "iu-Cans-CA"),
+    (0x045E, "am-ET"),
+    (0x0461, "ne-NP"),
+    (0x0462, "fy-NL"),
+    (0x0463, "ps-AF"),
+    (0x0464, "fil-PH"),
+    (0x0465, "dv-MV"),
+    (0x0468, "ha-Latn-NG"),
+    (0x046A, "yo-NG"),
+    (0x046B, "quz-BO"),
+    (0x046C, "nso-ZA"),
+    (0x046D, "ba-RU"),
+    (0x046E, "lb-LU"),
+    (0x046F, "kl-GL"),
+    (0x0470, "ig-NG"),
+    (0x0478, "ii-CN"),
+    (0x047A, "arn-CL"),
+    (0x047C, "moh-CA"),
+    (0x047E, "br-FR"),
+    (0x0480, "ug-CN"),
+    (0x0481, "mi-NZ"),
+    (0x0482, "oc-FR"),
+    (0x0483, "co-FR"),
+    (0x0484, "gsw-FR"),
+    (0x0485, "sah-RU"),
+    (0x0486, "qut-GT"),
+    (0x0487, "rw-RW"),
+    (0x0488, "wo-SN"),
+    (0x048C, "prs-AF"),
+    (0x0801, "ar-IQ"),
+    (0x0804, "zh-CN"),
+    (0x0807, "de-CH"),
+    (0x0809, "en-GB"),
+    (0x080A, "es-MX"),
+    (0x080C, "fr-BE"),
+    (0x0810, "it-CH"),
+    (0x0813, "nl-BE"),
+    (0x0814, "nn-NO"),
+    (0x0816, "pt-PT"),
+    (0x081A, "sr-Latn-CS"),
+    (0x081D, "sv-FI"),
+    (0x082C, "az-Cyrl-AZ"),
+    (0x082E, "dsb-DE"),
+    (0x083B, "se-SE"),
+    (0x083C, "ga-IE"),
+    (0x083E, "ms-BN"),
+    (0x0843, "uz-Cyrl-UZ"),
+    (0x0845, "bn-BD"),
+    (0x0850, "mn-Mong-CN"),
+    (0x085D, "iu-Latn-CA"),
+    (0x085F, "tzm-Latn-DZ"),
+    (0x086B, "quz-EC"),
+    (0x0C01, "ar-EG"),
+    (0x0C04, "zh-HK"),
+    (0x0C07, "de-AT"),
+    (0x0C09, "en-AU"),
+    (0x0C0A, "es-ES"),
+    (0x0C0C, "fr-CA"),
+    (0x0C1A, "sr-Cyrl-CS"),
+    (0x0C3B, "se-FI"),
+    (0x0C6B, "quz-PE"),
+    (0x1001, "ar-LY"),
+    (0x1004, "zh-SG"),
+    (0x1007, "de-LU"),
+    (0x1009, "en-CA"),
+    (0x100A, "es-GT"),
+    (0x100C, "fr-CH"),
+    (0x101A, "hr-BA"),
+    (0x103B, "smj-NO"),
+    (0x1401, "ar-DZ"),
+    (0x1404, "zh-MO"),
+    (0x1407, "de-LI"),
+    (0x1409, "en-NZ"),
+    (0x140A, "es-CR"),
+    (0x140C, "fr-LU"),
+    (0x141A, "bs-Latn-BA"),
+    (0x143B, "smj-SE"),
+    (0x1801, "ar-MA"),
+    (0x1809, "en-IE"),
+    (0x180A, "es-PA"),
+    (0x180C, "fr-MC"),
+    (0x181A, "sr-Latn-BA"),
+    (0x183B, "sma-NO"),
+    (0x1C01, "ar-TN"),
+    (0x1C09, "en-ZA"),
+    (0x1C0A, "es-DO"),
+    (0x1C1A, "sr-Cyrl-BA"),
+    (0x1C3B, "sma-SE"),
+    (0x2001, "ar-OM"),
+    (0x2009, "en-JM"),
+    (0x200A, "es-VE"),
+    (0x201A, "bs-Cyrl-BA"),
+    (0x203B, "sms-FI"),
+    (0x2401, "ar-YE"),
+    (0x2409, "en-029"),
+    (0x240A, "es-CO"),
+    (0x243B, "smn-FI"),
+    (0x2801, "ar-SY"),
+    (0x2809, "en-BZ"),
+    (0x280A, "es-PE"),
+    (0x2C01, "ar-JO"),
+    (0x2C09, "en-TT"),
+    (0x2C0A, "es-AR"),
+    (0x3001, "ar-LB"),
+    (0x3009, "en-ZW"),
+    (0x300A, "es-EC"),
+    (0x3401, "ar-KW"),
+    (0x3409, "en-PH"),
+    (0x340A, "es-CL"),
+    (0x3801, "ar-AE"),
+    (0x380A, "es-UY"),
+    (0x3C01, "ar-BH"),
+    (0x3C0A, "es-PY"),
+    (0x4001, "ar-QA"),
+    (0x4009, "en-IN"),
+    (0x400A, "es-BO"),
+    (0x4409, "en-MY"),
+    (0x440A, "es-SV"),
+    (0x4809, "en-SG"),
+    (0x480A, "es-HN"),
+    (0x4C0A, "es-NI"),
+    (0x500A, "es-PR"),
+    (0x540A, "es-US"),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::library::Library;
+
+    use super::*;
+
+    #[test]
+    fn language_tables_are_sorted() {
+        for table in [MAC_LANGUAGE_TAGS, MS_LANGUAGE_TAGS] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn decode_names() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        let mac_copyright = face
+            .sfnt_names()
+            .find(|name| {
+                name.platform_id == ffi::TT_PLATFORM_MACINTOSH
+                    && name.name_id == NameId::Copyright as u16
+            })
+            .unwrap();
+        assert_eq!(mac_copyright.language_tag.as_deref(), Some("en"));
+        assert!(mac_copyright
+            .decode()
+            .unwrap()
+            .starts_with("Digitized data copyright \u{A9}"));
+
+        let family = face.localized_name(NameId::FontFamily, "de-DE");
+        assert_eq!(family.as_deref(), Some("Fira Sans"));
+        assert_eq!(face.localized_name(NameId::SampleText, "en"), None);
+
+        // Fira Sans has no typographic names, so the legacy ones are used.
+        assert!(face
+            .sfnt_names()
+            .all(|name| name.name_id != NameId::TypographicFamily as u16));
+        let family = face.localized_name(NameId::TypographicFamily, "en");
+        assert_eq!(family.as_deref(), Some("Fira Sans"));
+        let subfamily = face.localized_name(NameId::WwsSubfamily, "en");
+        assert_eq!(subfamily.as_deref(), Some("Regular"));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{ffi, ffi_ext};
+use std::marker::PhantomData;
+
+/// How FreeType interprets the width and height of a `SizeRequest`.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SizeRequestType {
+    /// The nominal size, i.e. the EM square of the font.
+    Nominal = ffi::FT_SIZE_REQUEST_TYPE_NOMINAL,
+    /// The real dimension, i.e. the sum of the ascender and (minus of) the descender.
+    RealDim = ffi::FT_SIZE_REQUEST_TYPE_REAL_DIM,
+    /// The width and height of the font's bounding box.
+    BBox = ffi::FT_SIZE_REQUEST_TYPE_BBOX,
+    /// The maximum advance width and the real dimension, so that every glyph of a monospaced
+    /// font fits into a cell of exactly the requested size.
+    Cell = ffi::FT_SIZE_REQUEST_TYPE_CELL,
+    /// The width and height are horizontal and vertical scaling values in 16.16 format.
+    Scales = ffi::FT_SIZE_REQUEST_TYPE_SCALES,
+}
+
+/// A size request for `Face::request_size`.
+///
+/// Width and height are in 26.6 fractional points, or in 26.6 fractional pixels when both
+/// resolutions are zero (the default). A zero width means "same as height" and vice versa.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SizeRequest {
+    request_type: SizeRequestType,
+    width: ffi::FT_Long,
+    height: ffi::FT_Long,
+    horz_resolution: u32,
+    vert_resolution: u32,
+}
+
+impl SizeRequest {
+    pub fn new(request_type: SizeRequestType) -> Self {
+        SizeRequest {
+            request_type,
+            width: 0,
+            height: 0,
+            horz_resolution: 0,
+            vert_resolution: 0,
+        }
+    }
+
+    pub fn width(mut self, width: ffi::FT_Long) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: ffi::FT_Long) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the horizontal and vertical resolution in dpi. A zero resolution makes the
+    /// requested dimensions pixel values.
+    pub fn resolution(mut self, horz_resolution: u32, vert_resolution: u32) -> Self {
+        self.horz_resolution = horz_resolution;
+        self.vert_resolution = vert_resolution;
+        self
+    }
+
+    pub(crate) fn to_raw(self) -> ffi::FT_Size_RequestRec {
+        ffi::FT_Size_RequestRec {
+            size_request_type: self.request_type as ffi::FT_Size_Request_Type,
+            width: self.width,
+            height: self.height,
+            horiResolution: self.horz_resolution,
+            vertResolution: self.vert_resolution,
+        }
+    }
+}
+
+/// An additional size object of a face, created with `Face::new_size`.
+///
+/// Each size keeps its own character size and scaled metrics, so one face can be rendered at
+/// several sizes without resetting the active size every time. A size only takes effect once
+/// it is passed to `Face::activate_size`, and it is released when dropped.
+pub struct Size<'a> {
+    raw: ffi::FT_Size,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Size<'a> {
+    pub(crate) unsafe fn from_raw(raw: ffi::FT_Size) -> Self {
+        Size {
+            raw,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The scaled metrics of this size, as set by `Face::set_char_size` or
+    /// `Face::set_pixel_sizes` while it was active.
+    #[inline(always)]
+    pub fn metrics(&self) -> ffi::FT_Size_Metrics {
+        unsafe { (*self.raw).metrics }
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> ffi::FT_Size {
+        self.raw
+    }
+
+    #[inline(always)]
+    pub(crate) fn face_raw(&self) -> ffi::FT_Face {
+        unsafe { (*self.raw).face }
+    }
+}
+
+impl<'a> Drop for Size<'a> {
+    fn drop(&mut self) {
+        let err = unsafe { ffi_ext::FT_Done_Size(self.raw) };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop size");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{SizeRequest, SizeRequestType};
+    use crate::library::Library;
+
+    #[test]
+    fn independent_sizes() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        let small = face.new_size().unwrap();
+        let large = face.new_size().unwrap();
+        face.activate_size(&small).unwrap();
+        face.set_pixel_sizes(0, 12).unwrap();
+        face.activate_size(&large).unwrap();
+        face.set_pixel_sizes(0, 48).unwrap();
+
+        assert_eq!(small.metrics().y_ppem, 12);
+        assert_eq!(large.metrics().y_ppem, 48);
+        assert_eq!(face.size_metrics().unwrap().y_ppem, 48);
+
+        face.activate_size(&small).unwrap();
+        assert_eq!(face.size_metrics().unwrap().y_ppem, 12);
+
+        let other = library
+            .new_memory_face2(
+                &include_bytes!("../examples/assets/FiraSans-Regular.ttf")[..],
+                0,
+            )
+            .unwrap();
+        let foreign = other.new_size().unwrap();
+        assert!(face.activate_size(&foreign).is_err());
+    }
+
+    #[test]
+    fn request_size() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let face = library.new_face(fira_path, 0).unwrap();
+
+        face.request_size(&SizeRequest::new(SizeRequestType::Nominal).height(20 * 64))
+            .unwrap();
+        assert_eq!(face.size_metrics().unwrap().y_ppem, 20);
+
+        // A cell request never scales the two axes differently.
+        face.request_size(
+            &SizeRequest::new(SizeRequestType::Cell)
+                .width(8 * 64)
+                .height(40 * 64),
+        )
+        .unwrap();
+        let metrics = face.size_metrics().unwrap();
+        assert_eq!(metrics.x_scale, metrics.y_scale);
+        assert!(metrics.max_advance <= 8 * 64);
+
+        face.request_size(&SizeRequest::new(SizeRequestType::Scales).height(0x8000))
+            .unwrap();
+        assert_eq!(face.size_metrics().unwrap().y_scale, 0x8000);
+    }
+}
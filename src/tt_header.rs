@@ -0,0 +1,132 @@
+use crate::face::Face;
+use crate::{ffi, ffi_ext};
+
+#[derive(Copy, Clone)]
+pub struct TrueTypeHeaderTable {
+    raw: *const ffi_ext::TT_Header,
+}
+
+impl TrueTypeHeaderTable {
+    pub fn from_face(face: &mut Face) -> Option<Self> {
+        unsafe {
+            let head =
+                ffi::FT_Get_Sfnt_Table(face.raw_mut() as *mut ffi::FT_FaceRec, ffi::ft_sfnt_head)
+                    as *const ffi_ext::TT_Header;
+            if !head.is_null() {
+                Some(TrueTypeHeaderTable { raw: head })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn table_version(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).Table_Version }
+    }
+
+    #[inline(always)]
+    pub fn font_revision(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).Font_Revision }
+    }
+
+    #[inline(always)]
+    pub fn checksum_adjust(&self) -> ffi::FT_Long {
+        unsafe { (*self.raw).CheckSum_Adjust }
+    }
+
+    #[inline(always)]
+    pub fn magic_number(&self) -> ffi::FT_Long {
+        unsafe { (*self.raw).Magic_Number }
+    }
+
+    #[inline(always)]
+    pub fn flags(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Flags }
+    }
+
+    #[inline(always)]
+    pub fn units_per_em(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Units_Per_EM }
+    }
+
+    #[inline(always)]
+    pub fn created(&self) -> [ffi::FT_ULong; 2] {
+        unsafe { (*self.raw).Created }
+    }
+
+    #[inline(always)]
+    pub fn modified(&self) -> [ffi::FT_ULong; 2] {
+        unsafe { (*self.raw).Modified }
+    }
+
+    #[inline(always)]
+    pub fn x_min(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).xMin }
+    }
+
+    #[inline(always)]
+    pub fn y_min(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).yMin }
+    }
+
+    #[inline(always)]
+    pub fn x_max(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).xMax }
+    }
+
+    #[inline(always)]
+    pub fn y_max(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).yMax }
+    }
+
+    #[inline(always)]
+    pub fn mac_style(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Mac_Style }
+    }
+
+    #[inline(always)]
+    pub fn lowest_rec_ppem(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Lowest_Rec_PPEM }
+    }
+
+    #[inline(always)]
+    pub fn font_direction(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Font_Direction }
+    }
+
+    #[inline(always)]
+    pub fn index_to_loc_format(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Index_To_Loc_Format }
+    }
+
+    #[inline(always)]
+    pub fn glyph_data_format(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Glyph_Data_Format }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::library::Library;
+
+    use super::*;
+
+    /// Sanity-check reading the font header.
+    #[test]
+    fn font_header() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let mut face = library.new_face(fira_path, 0).unwrap();
+        let table = TrueTypeHeaderTable::from_face(&mut face).unwrap();
+
+        assert_eq!(table.magic_number(), 0x5F0F3CF5);
+        assert_eq!(table.units_per_em(), 1000);
+        assert_eq!(table.mac_style(), 0);
+        assert_eq!(table.index_to_loc_format(), 0);
+    }
+}
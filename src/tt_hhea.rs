@@ -0,0 +1,111 @@
+use crate::face::Face;
+use crate::{ffi, ffi_ext};
+
+#[derive(Copy, Clone)]
+pub struct TrueTypeHorizontalHeaderTable {
+    raw: *const ffi_ext::TT_HoriHeader,
+}
+
+impl TrueTypeHorizontalHeaderTable {
+    pub fn from_face(face: &mut Face) -> Option<Self> {
+        unsafe {
+            let hhea =
+                ffi::FT_Get_Sfnt_Table(face.raw_mut() as *mut ffi::FT_FaceRec, ffi::ft_sfnt_hhea)
+                    as *const ffi_ext::TT_HoriHeader;
+            if !hhea.is_null() {
+                Some(TrueTypeHorizontalHeaderTable { raw: hhea })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).Version }
+    }
+
+    #[inline(always)]
+    pub fn ascender(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Ascender }
+    }
+
+    #[inline(always)]
+    pub fn descender(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Descender }
+    }
+
+    #[inline(always)]
+    pub fn line_gap(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Line_Gap }
+    }
+
+    #[inline(always)]
+    pub fn advance_width_max(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).advance_Width_Max }
+    }
+
+    #[inline(always)]
+    pub fn min_left_side_bearing(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).min_Left_Side_Bearing }
+    }
+
+    #[inline(always)]
+    pub fn min_right_side_bearing(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).min_Right_Side_Bearing }
+    }
+
+    #[inline(always)]
+    pub fn x_max_extent(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).xMax_Extent }
+    }
+
+    #[inline(always)]
+    pub fn caret_slope_rise(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Slope_Rise }
+    }
+
+    #[inline(always)]
+    pub fn caret_slope_run(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Slope_Run }
+    }
+
+    #[inline(always)]
+    pub fn caret_offset(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Offset }
+    }
+
+    #[inline(always)]
+    pub fn metric_data_format(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).metric_Data_Format }
+    }
+
+    #[inline(always)]
+    pub fn number_of_hmetrics(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).number_Of_HMetrics }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::library::Library;
+
+    use super::*;
+
+    /// Sanity-check reading basic line metrics from the hhea table.
+    #[test]
+    fn line_metrics() {
+        let mut fira_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        fira_path.push("examples/assets/FiraSans-Regular.ttf");
+
+        let library = Library::init().unwrap();
+        let mut face = library.new_face(fira_path, 0).unwrap();
+        let table = TrueTypeHorizontalHeaderTable::from_face(&mut face).unwrap();
+
+        assert_eq!(table.ascender(), 1050);
+        assert_eq!(table.descender(), -350);
+        assert_eq!(table.line_gap(), 0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::face::Face;
+use crate::{ffi, ffi_ext};
+
+#[derive(Copy, Clone)]
+pub struct TrueTypeMaxProfileTable {
+    raw: *const ffi_ext::TT_MaxProfile,
+}
+
+impl TrueTypeMaxProfileTable {
+    pub fn from_face(face: &mut Face) -> Option<Self> {
+        unsafe {
+            let maxp =
+                ffi::FT_Get_Sfnt_Table(face.raw_mut() as *mut ffi::FT_FaceRec, ffi::ft_sfnt_maxp)
+                    as *const ffi_ext::TT_MaxProfile;
+            if !maxp.is_null() {
+                Some(TrueTypeMaxProfileTable { raw: maxp })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).version }
+    }
+
+    #[inline(always)]
+    pub fn num_glyphs(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).numGlyphs }
+    }
+
+    #[inline(always)]
+    pub fn max_points(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxPoints }
+    }
+
+    #[inline(always)]
+    pub fn max_contours(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxContours }
+    }
+
+    #[inline(always)]
+    pub fn max_composite_points(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxCompositePoints }
+    }
+
+    #[inline(always)]
+    pub fn max_composite_contours(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxCompositeContours }
+    }
+
+    #[inline(always)]
+    pub fn max_zones(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxZones }
+    }
+
+    #[inline(always)]
+    pub fn max_twilight_points(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxTwilightPoints }
+    }
+
+    #[inline(always)]
+    pub fn max_storage(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxStorage }
+    }
+
+    #[inline(always)]
+    pub fn max_function_defs(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxFunctionDefs }
+    }
+
+    #[inline(always)]
+    pub fn max_instruction_defs(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxInstructionDefs }
+    }
+
+    #[inline(always)]
+    pub fn max_stack_elements(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxStackElements }
+    }
+
+    #[inline(always)]
+    pub fn max_size_of_instructions(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxSizeOfInstructions }
+    }
+
+    #[inline(always)]
+    pub fn max_component_elements(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxComponentElements }
+    }
+
+    #[inline(always)]
+    pub fn max_component_depth(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).maxComponentDepth }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::face::Face;
+use crate::{ffi, ffi_ext};
+
+#[derive(Copy, Clone)]
+pub struct TrueTypePCLTable {
+    raw: *const ffi_ext::TT_PCLT,
+}
+
+impl TrueTypePCLTable {
+    pub fn from_face(face: &mut Face) -> Option<Self> {
+        unsafe {
+            let pclt =
+                ffi::FT_Get_Sfnt_Table(face.raw_mut() as *mut ffi::FT_FaceRec, ffi::ft_sfnt_pclt)
+                    as *const ffi_ext::TT_PCLT;
+            if !pclt.is_null() {
+                Some(TrueTypePCLTable { raw: pclt })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).Version }
+    }
+
+    #[inline(always)]
+    pub fn font_number(&self) -> ffi::FT_ULong {
+        unsafe { (*self.raw).FontNumber }
+    }
+
+    #[inline(always)]
+    pub fn pitch(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Pitch }
+    }
+
+    #[inline(always)]
+    pub fn x_height(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).xHeight }
+    }
+
+    #[inline(always)]
+    pub fn style(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).Style }
+    }
+
+    #[inline(always)]
+    pub fn type_family(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).TypeFamily }
+    }
+
+    #[inline(always)]
+    pub fn cap_height(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).CapHeight }
+    }
+
+    #[inline(always)]
+    pub fn symbol_set(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).SymbolSet }
+    }
+
+    #[inline(always)]
+    pub fn type_face(&self) -> [ffi::FT_Char; 16] {
+        unsafe { (*self.raw).TypeFace }
+    }
+
+    #[inline(always)]
+    pub fn character_complement(&self) -> [ffi::FT_Char; 8] {
+        unsafe { (*self.raw).CharacterComplement }
+    }
+
+    #[inline(always)]
+    pub fn file_name(&self) -> [ffi::FT_Char; 6] {
+        unsafe { (*self.raw).FileName }
+    }
+
+    #[inline(always)]
+    pub fn stroke_weight(&self) -> ffi::FT_Char {
+        unsafe { (*self.raw).StrokeWeight }
+    }
+
+    #[inline(always)]
+    pub fn width_type(&self) -> ffi::FT_Char {
+        unsafe { (*self.raw).WidthType }
+    }
+
+    #[inline(always)]
+    pub fn serif_style(&self) -> ffi::FT_Byte {
+        unsafe { (*self.raw).SerifStyle }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::face::Face;
+use crate::{ffi, ffi_ext};
+
+#[derive(Copy, Clone)]
+pub struct TrueTypeVerticalHeaderTable {
+    raw: *const ffi_ext::TT_VertHeader,
+}
+
+impl TrueTypeVerticalHeaderTable {
+    pub fn from_face(face: &mut Face) -> Option<Self> {
+        unsafe {
+            let vhea =
+                ffi::FT_Get_Sfnt_Table(face.raw_mut() as *mut ffi::FT_FaceRec, ffi::ft_sfnt_vhea)
+                    as *const ffi_ext::TT_VertHeader;
+            if !vhea.is_null() {
+                Some(TrueTypeVerticalHeaderTable { raw: vhea })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> ffi::FT_Fixed {
+        unsafe { (*self.raw).Version }
+    }
+
+    #[inline(always)]
+    pub fn ascender(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Ascender }
+    }
+
+    #[inline(always)]
+    pub fn descender(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Descender }
+    }
+
+    #[inline(always)]
+    pub fn line_gap(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).Line_Gap }
+    }
+
+    #[inline(always)]
+    pub fn advance_height_max(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).advance_Height_Max }
+    }
+
+    #[inline(always)]
+    pub fn min_top_side_bearing(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).min_Top_Side_Bearing }
+    }
+
+    #[inline(always)]
+    pub fn min_bottom_side_bearing(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).min_Bottom_Side_Bearing }
+    }
+
+    #[inline(always)]
+    pub fn y_max_extent(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).yMax_Extent }
+    }
+
+    #[inline(always)]
+    pub fn caret_slope_rise(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Slope_Rise }
+    }
+
+    #[inline(always)]
+    pub fn caret_slope_run(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Slope_Run }
+    }
+
+    #[inline(always)]
+    pub fn caret_offset(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).caret_Offset }
+    }
+
+    #[inline(always)]
+    pub fn metric_data_format(&self) -> ffi::FT_Short {
+        unsafe { (*self.raw).metric_Data_Format }
+    }
+
+    #[inline(always)]
+    pub fn number_of_vmetrics(&self) -> ffi::FT_UShort {
+        unsafe { (*self.raw).number_Of_VMetrics }
+    }
+}
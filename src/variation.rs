@@ -0,0 +1,113 @@
+use crate::{ffi, FtResult};
+use std::ffi::CStr;
+use std::slice;
+
+/// A design axis of a variable (OpenType variations or Multiple Masters) font, as returned by
+/// `Face::variation_axes`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariationAxis {
+    /// The axis name as reported by FreeType. For TrueType GX and OpenType variable fonts this
+    /// is only an English fallback; use `name_id` to look up a localized name.
+    pub name: Option<String>,
+    /// The four-byte axis tag, e.g. `wght` or `wdth`.
+    pub tag: u32,
+    /// The minimum design coordinate of the axis, in 16.16 format.
+    pub minimum: ffi::FT_Fixed,
+    /// The default design coordinate of the axis, in 16.16 format.
+    pub default: ffi::FT_Fixed,
+    /// The maximum design coordinate of the axis, in 16.16 format.
+    pub maximum: ffi::FT_Fixed,
+    /// The `name` table entry for the axis name.
+    pub name_id: u32,
+    /// Whether the font asks for the axis to be hidden from user interfaces.
+    pub hidden: bool,
+}
+
+/// A named instance (also called named style) of a variable font, as returned by
+/// `Face::named_instances`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInstance {
+    /// The design coordinates of the instance, one per axis, in 16.16 format.
+    pub coords: Vec<ffi::FT_Fixed>,
+    /// The `name` table entry for the instance's subfamily name.
+    pub name_id: u32,
+    /// The `name` table entry for the instance's PostScript name, if any.
+    pub postscript_name_id: Option<u32>,
+}
+
+/// Owned `FT_MM_Var` descriptor, released with `FT_Done_MM_Var` when dropped.
+pub(crate) struct MmVar {
+    library_raw: ffi::FT_Library,
+    raw: *mut ffi::FT_MM_Var,
+}
+
+impl MmVar {
+    pub(crate) unsafe fn from_raw(library_raw: ffi::FT_Library, raw: *mut ffi::FT_MM_Var) -> Self {
+        MmVar { library_raw, raw }
+    }
+
+    pub(crate) fn num_axis(&self) -> usize {
+        unsafe { (*self.raw).num_axis as usize }
+    }
+
+    pub(crate) fn axes(&self) -> FtResult<Vec<VariationAxis>> {
+        if self.num_axis() == 0 {
+            return Ok(Vec::new());
+        }
+        let axes = unsafe { slice::from_raw_parts((*self.raw).axis, self.num_axis()) };
+        axes.iter()
+            .enumerate()
+            .map(|(i, axis)| {
+                let mut flags = 0;
+                let err = unsafe { ffi::FT_Get_Var_Axis_Flags(self.raw, i as u32, &mut flags) };
+                if err != ffi::FT_Err_Ok {
+                    return Err(err.into());
+                }
+                let name = if axis.name.is_null() {
+                    None
+                } else {
+                    let name = unsafe { CStr::from_ptr(axis.name).to_bytes().to_vec() };
+                    String::from_utf8(name).ok()
+                };
+                Ok(VariationAxis {
+                    name,
+                    tag: axis.tag as u32,
+                    minimum: axis.minimum,
+                    default: axis.def,
+                    maximum: axis.maximum,
+                    name_id: axis.strid,
+                    hidden: flags & ffi::FT_VAR_AXIS_FLAG_HIDDEN != 0,
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn named_instances(&self) -> Vec<NamedInstance> {
+        let num_styles = unsafe { (*self.raw).num_namedstyles as usize };
+        if num_styles == 0 {
+            return Vec::new();
+        }
+        let styles = unsafe { slice::from_raw_parts((*self.raw).namedstyle, num_styles) };
+        styles
+            .iter()
+            .map(|style| NamedInstance {
+                coords: unsafe { slice::from_raw_parts(style.coords, self.num_axis()).to_vec() },
+                name_id: style.strid,
+                postscript_name_id: if style.psid == 0xFFFF {
+                    None
+                } else {
+                    Some(style.psid)
+                },
+            })
+            .collect()
+    }
+}
+
+impl Drop for MmVar {
+    fn drop(&mut self) {
+        let err = unsafe { ffi::FT_Done_MM_Var(self.library_raw, self.raw) };
+        if err != ffi::FT_Err_Ok {
+            panic!("Failed to drop MM var");
+        }
+    }
+}